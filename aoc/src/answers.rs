@@ -0,0 +1,148 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+/// The answers a day printed, keyed by part name, e.g. `Part 1`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Answers(pub BTreeMap<String, String>);
+
+impl Answers {
+    pub fn from_output(output: &str) -> Self {
+        Self(
+            output
+                .lines()
+                .filter_map(|line| {
+                    let (part, answer) = line.trim().split_once(": ")?;
+                    if part.starts_with("Part ") {
+                        Some((part.to_string(), answer.trim().to_string()))
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Describes each answer relative to the previous run, if there was one.
+    pub fn describe_changes(&self, previous: Option<&Self>) -> Vec<String> {
+        let mut parts = self.0.keys().collect::<Vec<&String>>();
+        if let Some(previous) = previous {
+            parts.extend(previous.0.keys().filter(|part| !self.0.contains_key(*part)));
+            parts.sort();
+        }
+        parts
+            .into_iter()
+            .map(|part| {
+                let current = self.0.get(part);
+                let before = previous.and_then(|previous| previous.0.get(part));
+                match (current, before, previous) {
+                    (Some(current), _, None) => format!("{}: {}", part, current),
+                    (Some(current), Some(before), _) if current == before => {
+                        format!("{}: {} (unchanged)", part, current)
+                    }
+                    (Some(current), Some(before), _) => {
+                        format!("{}: {} (was {})", part, current, before)
+                    }
+                    (Some(current), None, _) => format!("{}: {} (new)", part, current),
+                    (None, Some(before), _) => format!("{}: missing (was {})", part, before),
+                    (None, None, _) => unreachable!(),
+                }
+            })
+            .collect()
+    }
+
+    /// Lists the parts whose answers differ from the expected ones.
+    pub fn mismatches(&self, expected: &Self) -> Vec<String> {
+        expected
+            .0
+            .iter()
+            .filter_map(|(part, answer)| match self.0.get(part) {
+                Some(current) if current == answer => None,
+                Some(current) => Some(format!("{}: {} (expected {})", part, current, answer)),
+                None => Some(format!("{}: missing (expected {})", part, answer)),
+            })
+            .collect()
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(pairs: &[(&str, &str)]) -> Answers {
+        Answers(
+            pairs
+                .iter()
+                .map(|(part, answer)| (part.to_string(), answer.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn parses_answers_from_output() {
+        assert_eq!(
+            answers(&[("Part 1", "142"), ("Part 2", "281")]),
+            Answers::from_output("Compiling\nPart 1: 142\nnoise: 3\nPart 2: 281\n")
+        );
+        assert_eq!(Answers::default(), Answers::from_output(""));
+    }
+
+    #[test]
+    fn describes_answers_without_a_previous_run() {
+        assert_eq!(
+            vec!["Part 1: 142".to_string()],
+            answers(&[("Part 1", "142")]).describe_changes(None)
+        );
+    }
+
+    #[test]
+    fn describes_changes_from_previous_run() {
+        let previous = answers(&[("Part 1", "142"), ("Part 2", "200")]);
+        let current = answers(&[("Part 1", "142"), ("Part 3", "7")]);
+        assert_eq!(
+            vec![
+                "Part 1: 142 (unchanged)".to_string(),
+                "Part 2: missing (was 200)".to_string(),
+                "Part 3: 7 (new)".to_string(),
+            ],
+            current.describe_changes(Some(&previous))
+        );
+        let current = answers(&[("Part 1", "143"), ("Part 2", "200")]);
+        assert_eq!(
+            vec![
+                "Part 1: 143 (was 142)".to_string(),
+                "Part 2: 200 (unchanged)".to_string(),
+            ],
+            current.describe_changes(Some(&previous))
+        );
+    }
+
+    #[test]
+    fn finds_mismatches_against_expected_answers() {
+        let expected = answers(&[("Part 1", "142"), ("Part 2", "281")]);
+        assert_eq!(
+            Vec::<String>::new(),
+            answers(&[("Part 1", "142"), ("Part 2", "281")]).mismatches(&expected)
+        );
+        assert_eq!(
+            vec![
+                "Part 1: 141 (expected 142)".to_string(),
+                "Part 2: missing (expected 281)".to_string(),
+            ],
+            answers(&[("Part 1", "141"), ("Part 3", "1")]).mismatches(&expected)
+        );
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::answers::Answers;
+
+/// The days whose binaries accept `--lint`.
+pub const LINTED_DAYS: [u8; 1] = [5];
+
+/// The days whose binaries accept `--fmt`.
+pub const FORMATTED_DAYS: [u8; 6] = [2, 4, 5, 7, 23, 24];
+
+pub fn day_dir_name(day: u8) -> String {
+    format!("day-{:02}", day)
+}
+
+/// Finds the day's crate in `start` or the closest ancestor that has it, so
+/// the runner works from the repo root, a day directory or `aoc` itself.
+pub fn find_day_dir(start: &Path, day: u8) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|directory| directory.join(day_dir_name(day)))
+        .find(|directory| directory.join("Cargo.toml").is_file())
+}
+
+/// Only these paths under a day's crate should trigger a rerun.
+pub fn is_watched(day_dir: &Path, path: &Path) -> bool {
+    match path
+        .strip_prefix(day_dir)
+        .ok()
+        .and_then(|relative| relative.components().next())
+    {
+        Some(component) => matches!(
+            component.as_os_str().to_str(),
+            Some("src" | "examples" | "input.txt" | "Cargo.toml")
+        ),
+        None => false,
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn run_tests(day_dir: &Path) -> bool {
+    Command::new("cargo")
+        .args(["test", "--quiet"])
+        .current_dir(day_dir)
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn run_parts(day_dir: &Path, input: &Path) -> Answers {
+    match Command::new("cargo")
+        .args(["run", "--quiet", "--release", "--"])
+        .arg(input)
+        .current_dir(day_dir)
+        .output()
+    {
+        Ok(output) => {
+            if !output.status.success() {
+                eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            }
+            Answers::from_output(&String::from_utf8_lossy(&output.stdout))
+        }
+        Err(error) => {
+            eprintln!("Unable to run {}: {}", day_dir.display(), error);
+            Answers::default()
+        }
+    }
+}
+
+/// Runs the day's linter over an input and returns whether it was clean,
+/// along with its report.
+#[cfg(not(tarpaulin_include))]
+pub fn run_lint(day_dir: &Path, input: &Path) -> (bool, String) {
+    match Command::new("cargo")
+        .args(["run", "--quiet", "--release", "--"])
+        .arg(input)
+        .arg("--lint")
+        .current_dir(day_dir)
+        .output()
+    {
+        Ok(output) => {
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            (
+                output.status.success(),
+                String::from_utf8_lossy(&output.stdout).to_string(),
+            )
+        }
+        Err(error) => (
+            false,
+            format!("Unable to run {}: {}", day_dir.display(), error),
+        ),
+    }
+}
+
+/// Runs the day's formatter over an input. On failure the error is the
+/// last line the day printed, after any build output.
+#[cfg(not(tarpaulin_include))]
+pub fn run_fmt(day_dir: &Path, input: &Path) -> Result<String, String> {
+    match Command::new("cargo")
+        .args(["run", "--quiet", "--release", "--"])
+        .arg(input)
+        .arg("--fmt")
+        .current_dir(day_dir)
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        }
+        Ok(output) => Err(String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .last()
+            .unwrap_or("")
+            .to_string()),
+        Err(error) => Err(format!("Unable to run {}: {}", day_dir.display(), error)),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_day_dirs() {
+        assert_eq!("day-01", day_dir_name(1));
+        assert_eq!("day-25", day_dir_name(25));
+    }
+
+    #[test]
+    fn finds_day_dir_from_ancestors() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        assert_eq!(
+            Some(root.join("day-05")),
+            find_day_dir(Path::new(env!("CARGO_MANIFEST_DIR")), 5)
+        );
+        assert_eq!(Some(root.join("day-05")), find_day_dir(root, 5));
+        assert_eq!(None, find_day_dir(root, 99));
+    }
+
+    #[test]
+    fn only_watches_sources_and_inputs() {
+        let day_dir = Path::new("/repo/day-05");
+        assert!(is_watched(day_dir, &day_dir.join("src/main.rs")));
+        assert!(is_watched(day_dir, &day_dir.join("input.txt")));
+        assert!(is_watched(day_dir, &day_dir.join("examples/small.txt")));
+        assert!(is_watched(day_dir, &day_dir.join("Cargo.toml")));
+        assert!(!is_watched(day_dir, &day_dir.join("target/debug/day-05")));
+        assert!(!is_watched(day_dir, &day_dir.join("Cargo.lock")));
+        assert!(!is_watched(day_dir, Path::new("/repo/day-06/src/main.rs")));
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+use crate::answers::Answers;
+
+/// A named puzzle input, e.g. `inputs/day05/alice.txt`, and the answers
+/// recorded next to it in `inputs/day05/alice.answers`, if any.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InputSet {
+    pub name: String,
+    pub input: PathBuf,
+    pub answers: Option<Answers>,
+}
+
+impl InputSet {
+    fn from_path(input: PathBuf) -> Option<Self> {
+        let name = input.file_stem()?.to_str()?.to_string();
+        let answers = read_to_string(input.with_extension("answers"))
+            .ok()
+            .map(|answers| Answers::from_output(&answers));
+        Some(Self {
+            name,
+            input,
+            answers,
+        })
+    }
+}
+
+/// Where a day's named inputs live, relative to the repo root.
+pub fn inputs_dir(root: &Path, day: u8) -> PathBuf {
+    root.join("inputs").join(format!("day{:02}", day))
+}
+
+/// Lists every named input for a day, sorted by name.
+pub fn find_input_sets(inputs_dir: &Path) -> Vec<InputSet> {
+    let mut input_sets = match read_dir(inputs_dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| Some("txt") == path.extension().and_then(|extension| extension.to_str()))
+            .filter_map(InputSet::from_path)
+            .collect::<Vec<InputSet>>(),
+        Err(_) => Vec::new(),
+    };
+    input_sets.sort_by(|first, second| first.name.cmp(&second.name));
+    input_sets
+}
+
+/// Which inputs a runner command should use.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Selection {
+    /// The day's own `input.txt`
+    Default,
+    /// A single named input from the input store
+    Named(String),
+    /// Every named input from the input store
+    All,
+}
+
+pub fn select_input_sets(
+    day_dir: &Path,
+    inputs_dir: &Path,
+    selection: &Selection,
+) -> Result<Vec<InputSet>, String> {
+    match selection {
+        Selection::Default => Ok(vec![InputSet {
+            name: "input".to_string(),
+            input: day_dir.join("input.txt"),
+            answers: None,
+        }]),
+        Selection::Named(name) => find_input_sets(inputs_dir)
+            .into_iter()
+            .find(|input_set| *name == input_set.name)
+            .map(|input_set| vec![input_set])
+            .ok_or(format!(
+                "No input set named {} in {}",
+                name,
+                inputs_dir.display()
+            )),
+        Selection::All => {
+            let input_sets = find_input_sets(inputs_dir);
+            if input_sets.is_empty() {
+                Err(format!("No input sets in {}", inputs_dir.display()))
+            } else {
+                Ok(input_sets)
+            }
+        }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+
+    #[test]
+    fn names_inputs_dir_by_day() {
+        assert_eq!(
+            PathBuf::from("/repo/inputs/day05"),
+            inputs_dir(Path::new("/repo"), 5)
+        );
+    }
+
+    #[test]
+    fn finds_input_sets_with_their_answers() {
+        let directory = temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        create_dir_all(&directory).unwrap();
+        write(directory.join("bob.txt"), "1abc2\n").unwrap();
+        write(directory.join("alice.txt"), "1abc2\n").unwrap();
+        write(directory.join("alice.answers"), "Part 1: 12\nPart 2: 12\n").unwrap();
+        write(directory.join("notes.md"), "ignored").unwrap();
+        let input_sets = find_input_sets(&directory);
+        remove_dir_all(&directory).unwrap();
+        assert_eq!(
+            vec![
+                InputSet {
+                    name: "alice".to_string(),
+                    input: directory.join("alice.txt"),
+                    answers: Some(Answers::from_output("Part 1: 12\nPart 2: 12")),
+                },
+                InputSet {
+                    name: "bob".to_string(),
+                    input: directory.join("bob.txt"),
+                    answers: None,
+                },
+            ],
+            input_sets
+        );
+    }
+
+    #[test]
+    fn selects_input_sets() {
+        let directory = temp_dir().join(format!("aoc-select-{}", std::process::id()));
+        create_dir_all(&directory).unwrap();
+        write(directory.join("alice.txt"), "1abc2\n").unwrap();
+        write(directory.join("bob.txt"), "1abc2\n").unwrap();
+        let day_dir = Path::new("/repo/day-01");
+        let default = select_input_sets(day_dir, &directory, &Selection::Default);
+        let alice = select_input_sets(day_dir, &directory, &Selection::Named("alice".to_string()));
+        let carol = select_input_sets(day_dir, &directory, &Selection::Named("carol".to_string()));
+        let all = select_input_sets(day_dir, &directory, &Selection::All);
+        remove_dir_all(&directory).unwrap();
+        assert_eq!(
+            vec![day_dir.join("input.txt")],
+            default
+                .unwrap()
+                .into_iter()
+                .map(|input_set| input_set.input)
+                .collect::<Vec<PathBuf>>()
+        );
+        assert_eq!(
+            vec!["alice".to_string()],
+            alice
+                .unwrap()
+                .into_iter()
+                .map(|input_set| input_set.name)
+                .collect::<Vec<String>>()
+        );
+        assert!(carol.is_err());
+        assert_eq!(2, all.unwrap().len());
+        assert!(select_input_sets(day_dir, &directory, &Selection::All).is_err());
+    }
+
+    #[test]
+    fn finds_no_input_sets_without_a_directory() {
+        assert_eq!(
+            Vec::<InputSet>::new(),
+            find_input_sets(Path::new("/does/not/exist"))
+        );
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env::current_dir;
+use std::fs::{read_to_string, write};
+use std::path::PathBuf;
+use std::process::exit;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+
+mod answers;
+mod day;
+mod inputs;
+mod runner;
+mod watch;
+
+use inputs::Selection;
+
+#[derive(Parser)]
+#[command(about = "Runs and watches the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+struct InputSelection {
+    /// Use a named input from inputs/dayNN/<name>.txt instead of input.txt
+    #[arg(long, conflicts_with = "all_inputs")]
+    input_set: Option<String>,
+    /// Use every named input from inputs/dayNN
+    #[arg(long)]
+    all_inputs: bool,
+}
+
+impl InputSelection {
+    fn selection(&self) -> Selection {
+        match (&self.input_set, self.all_inputs) {
+            (Some(name), _) => Selection::Named(name.clone()),
+            (None, true) => Selection::All,
+            (None, false) => Selection::Default,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's parts and print the answers
+    Run {
+        #[arg(long)]
+        day: u8,
+        #[command(flatten)]
+        inputs: InputSelection,
+    },
+    /// Rerun a day's tests and parts whenever its sources or inputs change
+    Watch {
+        #[arg(long)]
+        day: u8,
+        #[command(flatten)]
+        inputs: InputSelection,
+        /// Milliseconds to wait for saves to settle before rerunning
+        #[arg(long, default_value_t = 500)]
+        debounce: u64,
+    },
+    /// Check a day's inputs for problems its solver would skip or misread
+    Lint {
+        #[arg(long)]
+        day: u8,
+        #[command(flatten)]
+        inputs: InputSelection,
+    },
+    /// Rewrite a day's inputs in canonical puzzle format
+    Fmt {
+        #[arg(long)]
+        day: u8,
+        #[command(flatten)]
+        inputs: InputSelection,
+        /// Only report inputs that would change
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+#[cfg(not(tarpaulin_include))]
+fn day_dir(day: u8) -> PathBuf {
+    let start = current_dir().expect("Unable to read current directory");
+    match day::find_day_dir(&start, day) {
+        Some(day_dir) => day_dir
+            .canonicalize()
+            .expect("Unable to resolve day directory"),
+        None => {
+            eprintln!("Unable to find {}", day::day_dir_name(day));
+            exit(1);
+        }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+fn main() {
+    match Cli::parse().command {
+        Command::Run { day, inputs } => {
+            let day_dir = day_dir(day);
+            let inputs_dir = inputs::inputs_dir(day_dir.parent().unwrap(), day);
+            let input_sets = inputs::select_input_sets(&day_dir, &inputs_dir, &inputs.selection())
+                .unwrap_or_else(|error| {
+                    eprintln!("{}", error);
+                    exit(1);
+                });
+            let (_, all_match) = runner::run_input_sets(&day_dir, &input_sets, None);
+            if !all_match {
+                exit(1);
+            }
+        }
+        Command::Watch {
+            day,
+            inputs,
+            debounce,
+        } => {
+            let day_dir = day_dir(day);
+            let inputs_dir = inputs::inputs_dir(day_dir.parent().unwrap(), day);
+            watch::watch(
+                &day_dir,
+                &inputs_dir,
+                &inputs.selection(),
+                Duration::from_millis(debounce),
+            )
+            .expect("Unable to watch day");
+        }
+        Command::Lint { day, inputs } => {
+            if !day::LINTED_DAYS.contains(&day) {
+                eprintln!("{} has no linter", day::day_dir_name(day));
+                exit(1);
+            }
+            let day_dir = day_dir(day);
+            let inputs_dir = inputs::inputs_dir(day_dir.parent().unwrap(), day);
+            let input_sets = inputs::select_input_sets(&day_dir, &inputs_dir, &inputs.selection())
+                .unwrap_or_else(|error| {
+                    eprintln!("{}", error);
+                    exit(1);
+                });
+            let mut all_clean = true;
+            for input_set in input_sets {
+                println!("[{}]", input_set.name);
+                let (clean, report) = day::run_lint(&day_dir, &input_set.input);
+                if clean {
+                    println!("  clean");
+                } else {
+                    all_clean = false;
+                    for line in report.lines() {
+                        println!("  {}", line);
+                    }
+                }
+            }
+            if !all_clean {
+                exit(1);
+            }
+        }
+        Command::Fmt { day, inputs, check } => {
+            if !day::FORMATTED_DAYS.contains(&day) {
+                eprintln!("{} has no formatter", day::day_dir_name(day));
+                exit(1);
+            }
+            let day_dir = day_dir(day);
+            let inputs_dir = inputs::inputs_dir(day_dir.parent().unwrap(), day);
+            let input_sets = inputs::select_input_sets(&day_dir, &inputs_dir, &inputs.selection())
+                .unwrap_or_else(|error| {
+                    eprintln!("{}", error);
+                    exit(1);
+                });
+            let mut all_formatted = true;
+            for input_set in input_sets {
+                let original = read_to_string(&input_set.input).expect("Unable to read input");
+                let status = match day::run_fmt(&day_dir, &input_set.input) {
+                    Err(error) => {
+                        all_formatted = false;
+                        format!("unable to format: {}", error)
+                    }
+                    Ok(formatted) if formatted == original => "formatted".to_string(),
+                    Ok(_) if check => {
+                        all_formatted = false;
+                        "needs formatting".to_string()
+                    }
+                    Ok(formatted) => {
+                        write(&input_set.input, formatted).expect("Unable to write input");
+                        "reformatted".to_string()
+                    }
+                };
+                println!("[{}] {}", input_set.name, status);
+            }
+            if !all_formatted {
+                exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_input_selection() {
+        let selection = |args: &[&str]| match Cli::try_parse_from(args).unwrap().command {
+            Command::Run { inputs, .. } => inputs.selection(),
+            Command::Watch { inputs, .. } => inputs.selection(),
+            Command::Lint { inputs, .. } => inputs.selection(),
+            Command::Fmt { inputs, .. } => inputs.selection(),
+        };
+        assert_eq!(Selection::Default, selection(&["aoc", "run", "--day", "1"]));
+        assert_eq!(
+            Selection::Named("alice".to_string()),
+            selection(&["aoc", "run", "--day", "1", "--input-set", "alice"])
+        );
+        assert_eq!(
+            Selection::All,
+            selection(&["aoc", "watch", "--day", "1", "--all-inputs"])
+        );
+        assert_eq!(
+            Selection::Named("bob".to_string()),
+            selection(&["aoc", "lint", "--day", "5", "--input-set", "bob"])
+        );
+        assert_eq!(
+            Selection::All,
+            selection(&["aoc", "fmt", "--day", "7", "--all-inputs", "--check"])
+        );
+        assert!(Cli::try_parse_from([
+            "aoc",
+            "run",
+            "--day",
+            "1",
+            "--input-set",
+            "alice",
+            "--all-inputs"
+        ])
+        .is_err());
+    }
+}
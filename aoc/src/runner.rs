@@ -0,0 +1,54 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::answers::Answers;
+use crate::day::run_parts;
+use crate::inputs::InputSet;
+
+/// Runs the day's parts against each input set and prints the answers next
+/// to the previous run's. Returns the new answers and whether every input
+/// set with recorded answers matched them.
+#[cfg(not(tarpaulin_include))]
+pub fn run_input_sets(
+    day_dir: &Path,
+    input_sets: &[InputSet],
+    previous: Option<&BTreeMap<String, Answers>>,
+) -> (BTreeMap<String, Answers>, bool) {
+    let mut results = BTreeMap::new();
+    let mut all_match = true;
+    for input_set in input_sets {
+        println!("[{}]", input_set.name);
+        let answers = run_parts(day_dir, &input_set.input);
+        let before = previous.map(|previous| previous.get(&input_set.name));
+        for line in answers.describe_changes(before.flatten()) {
+            println!("  {}", line);
+        }
+        if let Some(expected) = &input_set.answers {
+            let mismatches = answers.mismatches(expected);
+            if mismatches.is_empty() {
+                println!("  matches recorded answers");
+            } else {
+                all_match = false;
+                for line in mismatches {
+                    println!("  MISMATCH {}", line);
+                }
+            }
+        }
+        results.insert(input_set.name.clone(), answers);
+    }
+    (results, all_match)
+}
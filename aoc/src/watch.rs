@@ -0,0 +1,83 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fs::create_dir_all;
+use std::path::Path;
+use std::sync::mpsc::channel;
+use std::time::Duration;
+
+use notify_debouncer_mini::new_debouncer;
+use notify_debouncer_mini::notify::{Error, RecursiveMode, Result};
+
+use crate::answers::Answers;
+use crate::day::{is_watched, run_tests};
+use crate::inputs::{select_input_sets, Selection};
+use crate::runner::run_input_sets;
+
+#[cfg(not(tarpaulin_include))]
+fn run_once(
+    day_dir: &Path,
+    inputs_dir: &Path,
+    selection: &Selection,
+    previous: Option<BTreeMap<String, Answers>>,
+) -> Option<BTreeMap<String, Answers>> {
+    println!("--- {} ---", day_dir.display());
+    if !run_tests(day_dir) {
+        println!("Tests failed; skipping parts");
+        return previous;
+    }
+    match select_input_sets(day_dir, inputs_dir, selection) {
+        Ok(input_sets) => Some(run_input_sets(day_dir, &input_sets, previous.as_ref()).0),
+        Err(error) => {
+            eprintln!("{}", error);
+            previous
+        }
+    }
+}
+
+/// Reruns the day's tests and parts whenever its sources or inputs change.
+/// Saves that land within `debounce` of each other trigger a single rerun.
+/// The inputs directory is created if needed so sets added later are seen.
+#[cfg(not(tarpaulin_include))]
+pub fn watch(
+    day_dir: &Path,
+    inputs_dir: &Path,
+    selection: &Selection,
+    debounce: Duration,
+) -> Result<()> {
+    let (sender, receiver) = channel();
+    let mut debouncer = new_debouncer(debounce, sender)?;
+    debouncer
+        .watcher()
+        .watch(day_dir, RecursiveMode::Recursive)?;
+    create_dir_all(inputs_dir).map_err(Error::io)?;
+    debouncer
+        .watcher()
+        .watch(inputs_dir, RecursiveMode::Recursive)?;
+    let mut previous = run_once(day_dir, inputs_dir, selection, None);
+    for result in receiver {
+        match result {
+            Ok(events) => {
+                if events.iter().any(|event| {
+                    is_watched(day_dir, &event.path) || event.path.starts_with(inputs_dir)
+                }) {
+                    previous = run_once(day_dir, inputs_dir, selection, previous);
+                }
+            }
+            Err(error) => eprintln!("Watch error: {}", error),
+        }
+    }
+    Ok(())
+}
@@ -12,25 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs::read_to_string;
+use day_XX::{part1, part2};
 
 #[cfg(not(tarpaulin_include))]
 fn main() {
-    let input = read_to_string("input.txt").expect("Unable to read input file");
+    let input = input::read(&[]);
     println!("Part 1: {}", part1(input.clone()));
     println!("Part 2: {}", part2(input));
 }
-
-fn part1(input: String) -> usize {
-    todo!()
-}
-
-fn part2(input: String) -> usize {
-    todo!()
-}
-
-#[cfg(not(tarpaulin_include))]
-#[cfg(test)]
-mod tests {
-    use super::*;
-}
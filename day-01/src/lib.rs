@@ -0,0 +1,526 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advent of Code 2023 day 1: Trebuchet?!
+
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub mod vocabulary;
+
+use vocabulary::Vocabulary;
+
+/// The digits `0` through `9`.
+pub const DIGITS: [(&str, usize); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// The spelled-out digits `one` through `nine`.
+pub const DIGIT_WORDS: [(&str, usize); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// A token found in a line, with its byte offsets into the original line.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DigitMatch {
+    pub value: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// The first and last tokens found in a line.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Calibration {
+    pub first: DigitMatch,
+    pub last: DigitMatch,
+}
+
+impl Calibration {
+    /// The two-digit value formed by the leading digit of the first token
+    /// and the trailing digit of the last.
+    pub fn value(&self) -> usize {
+        let mut first = self.first.value;
+        while 10 <= first {
+            first /= 10;
+        }
+        first * 10 + self.last.value % 10
+    }
+}
+
+/// Finds calibration digits with a single pass of an Aho-Corasick automaton
+/// over each line. Matches may overlap, so `eightwo` yields both 8 and 2.
+pub struct CalibrationDecoder {
+    automaton: AhoCorasick,
+    values: Vec<usize>,
+}
+
+impl CalibrationDecoder {
+    /// Builds a decoder from a table of tokens and the numbers they stand for.
+    /// Tokens match case-insensitively and only on whole graphemes.
+    pub fn new<T: AsRef<str>>(tokens: &[(T, usize)]) -> Self {
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::Standard)
+            .build(tokens.iter().map(|(token, _)| fold(token.as_ref())))
+            .expect("Unable to build calibration automaton");
+        Self {
+            automaton,
+            values: tokens.iter().map(|(_, value)| *value).collect(),
+        }
+    }
+
+    /// Builds a decoder for the digits plus a vocabulary's words.
+    pub fn from_vocabulary(vocabulary: &Vocabulary) -> Self {
+        let tokens = DIGITS
+            .iter()
+            .map(|(digit, value)| (digit.to_string(), *value))
+            .chain(vocabulary.words.iter().cloned())
+            .collect::<Vec<(String, usize)>>();
+        Self::new(&tokens)
+    }
+
+    /// Finds the first and last tokens in a line, or `None` if it has none.
+    /// When tokens start at the same place the longest one wins, so with
+    /// teens `sixteen` beats `six`.
+    pub fn decode(&self, line: &str) -> Option<Calibration> {
+        let (line, offsets) = fold_with_offsets(line);
+        let boundaries = line
+            .grapheme_indices(true)
+            .map(|(index, _)| index)
+            .chain([line.len()])
+            .collect::<Vec<usize>>();
+        let mut calibration: Option<Calibration> = None;
+        for found in self.automaton.find_overlapping_iter(&line) {
+            if boundaries.binary_search(&found.start()).is_err()
+                || boundaries.binary_search(&found.end()).is_err()
+            {
+                continue;
+            }
+            let digit = DigitMatch {
+                value: self.values[found.pattern().as_usize()],
+                start: offsets[found.start()],
+                end: offsets[found.end()],
+            };
+            let key = |digit: &DigitMatch| (digit.start, digit.end);
+            calibration = Some(match calibration {
+                None => Calibration {
+                    first: digit,
+                    last: digit,
+                },
+                Some(Calibration { first, last }) => Calibration {
+                    first: if digit.start < first.start
+                        || (digit.start == first.start && digit.end > first.end)
+                    {
+                        digit
+                    } else {
+                        first
+                    },
+                    last: if key(&digit) > key(&last) {
+                        digit
+                    } else {
+                        last
+                    },
+                },
+            });
+        }
+        calibration
+    }
+
+    /// Sorts a line into valid, single-digit or digit-less.
+    pub fn classify(&self, line: &str) -> LineResult {
+        match self.decode(line) {
+            None => LineResult::NoDigits,
+            Some(calibration) if calibration.first == calibration.last => {
+                LineResult::SingleDigit(calibration)
+            }
+            Some(calibration) => LineResult::Valid(calibration),
+        }
+    }
+
+    /// Lists the 1-based numbers of every line that isn't plainly valid.
+    pub fn report(&self, input: &str) -> CalibrationReport {
+        let mut report = CalibrationReport::default();
+        for (index, line) in input.lines().enumerate() {
+            match self.classify(line) {
+                LineResult::Valid(_) => {}
+                LineResult::SingleDigit(_) => report.single_digit.push(index + 1),
+                LineResult::NoDigits => report.no_digits.push(index + 1),
+            }
+        }
+        report
+    }
+
+    /// Sums the calibration value of every line. Single-digit lines count,
+    /// as the puzzle reads `treb7uchet` as 77. Digit-less lines fail the
+    /// whole sum in strict mode, with a report listing just those lines, and
+    /// are skipped in lenient mode.
+    pub fn sum(&self, input: &str, mode: Mode) -> Result<usize, CalibrationReport> {
+        let mut sum = 0;
+        let mut report = CalibrationReport::default();
+        for (index, line) in input.lines().enumerate() {
+            match self.classify(line) {
+                LineResult::Valid(calibration) | LineResult::SingleDigit(calibration) => {
+                    sum += calibration.value()
+                }
+                LineResult::NoDigits => report.no_digits.push(index + 1),
+            }
+        }
+        if Mode::Strict == mode && !report.is_empty() {
+            return Err(report);
+        }
+        Ok(sum)
+    }
+}
+
+/// What the decoder made of a single line.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LineResult {
+    /// At least two tokens, e.g. `1abc2`
+    Valid(Calibration),
+    /// Exactly one token, used as both digits, e.g. `treb7uchet`
+    SingleDigit(Calibration),
+    /// No tokens at all, including blank lines
+    NoDigits,
+}
+
+/// How to treat lines with no digits.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Mode {
+    /// Fail with a report
+    #[default]
+    Strict,
+    /// Skip the line
+    Lenient,
+}
+
+/// The 1-based line numbers that need attention in a calibration document.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct CalibrationReport {
+    pub no_digits: Vec<usize>,
+    pub single_digit: Vec<usize>,
+}
+
+impl CalibrationReport {
+    /// Whether every line had at least two tokens.
+    pub fn is_empty(&self) -> bool {
+        self.no_digits.is_empty() && self.single_digit.is_empty()
+    }
+}
+
+impl std::fmt::Display for CalibrationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let join = |lines: &[usize]| {
+            lines
+                .iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        if !self.no_digits.is_empty() {
+            writeln!(f, "Lines with no digits: {}", join(&self.no_digits))?;
+        }
+        if !self.single_digit.is_empty() {
+            writeln!(f, "Lines with a single digit: {}", join(&self.single_digit))?;
+        }
+        Ok(())
+    }
+}
+
+/// Puts text into the form tokens are matched in: composed (NFC) and
+/// lowercased, so `FÜNF` and `fu\u{308}nf` both read as `fünf`.
+pub fn fold(text: &str) -> String {
+    fold_with_offsets(text).0
+}
+
+/// Folds text one grapheme at a time, also returning, for every byte offset
+/// into the folded text, where that grapheme started in the original.
+fn fold_with_offsets(text: &str) -> (String, Vec<usize>) {
+    let mut folded = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    for (start, grapheme) in text.grapheme_indices(true) {
+        folded.extend(grapheme.nfc().flat_map(char::to_lowercase).nfc());
+        offsets.resize(folded.len(), start);
+    }
+    offsets.push(text.len());
+    (folded, offsets)
+}
+
+/// Sums the calibration values built from each line's first and last digit,
+/// failing with a report if any line has no digits.
+pub fn part1(input: String) -> Result<usize, CalibrationReport> {
+    part1_with_mode(input, Mode::Strict)
+}
+
+/// Like [`part1`], skipping digit-less lines in lenient mode.
+pub fn part1_with_mode(input: String, mode: Mode) -> Result<usize, CalibrationReport> {
+    CalibrationDecoder::new(&DIGITS).sum(&input, mode)
+}
+
+/// Sums the calibration values when spelled-out digits count too, failing
+/// with a report if any line has none.
+pub fn part2(input: String) -> Result<usize, CalibrationReport> {
+    part2_with_vocabulary(
+        input,
+        &Vocabulary::builtin("english").unwrap(),
+        Mode::Strict,
+    )
+}
+
+/// Sums the calibration values when the vocabulary's words count too.
+pub fn part2_with_vocabulary(
+    input: String,
+    vocabulary: &Vocabulary,
+    mode: Mode,
+) -> Result<usize, CalibrationReport> {
+    CalibrationDecoder::from_vocabulary(vocabulary).sum(&input, mode)
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words_decoder() -> CalibrationDecoder {
+        decoder_for("english")
+    }
+
+    fn decoder_for(name: &str) -> CalibrationDecoder {
+        CalibrationDecoder::from_vocabulary(&Vocabulary::builtin(name).unwrap())
+    }
+
+    #[test]
+    fn decoder_finds_first_and_last_with_positions() {
+        assert_eq!(
+            Some(Calibration {
+                first: DigitMatch {
+                    value: 2,
+                    start: 1,
+                    end: 4,
+                },
+                last: DigitMatch {
+                    value: 4,
+                    start: 7,
+                    end: 11,
+                },
+            }),
+            words_decoder().decode("xtwone3four")
+        );
+        assert_eq!(
+            Some(Calibration {
+                first: DigitMatch {
+                    value: 7,
+                    start: 4,
+                    end: 5,
+                },
+                last: DigitMatch {
+                    value: 7,
+                    start: 4,
+                    end: 5,
+                },
+            }),
+            CalibrationDecoder::new(&DIGITS).decode("treb7uchet")
+        );
+        assert_eq!(None, CalibrationDecoder::new(&DIGITS).decode("abc"));
+    }
+
+    #[test]
+    fn decoder_handles_overlapping_words() {
+        let decoder = words_decoder();
+        assert_eq!(82, decoder.decode("eightwo").unwrap().value());
+        assert_eq!(18, decoder.decode("oneight").unwrap().value());
+        assert_eq!(83, decoder.decode("eighthree").unwrap().value());
+        assert_eq!(79, decoder.decode("sevenine").unwrap().value());
+        assert_eq!(11, decoder.decode("ONE").unwrap().value());
+    }
+
+    #[test]
+    fn decoder_reads_multi_digit_words() {
+        let decoder = decoder_for("english-extended");
+        assert_eq!(15, decoder.decode("fifteen").unwrap().value());
+        assert_eq!(16, decoder.decode("sixteen").unwrap().value());
+        assert_eq!(10, decoder.decode("ten2zero").unwrap().value());
+        assert_eq!(12, decoder.decode("seventeenx2").unwrap().value());
+    }
+
+    #[test]
+    fn decoder_reads_other_languages() {
+        assert_eq!(
+            12,
+            decoder_for("german").decode("xeinsqzwei").unwrap().value()
+        );
+        assert_eq!(55, decoder_for("german").decode("FÜNF").unwrap().value());
+        assert_eq!(
+            5,
+            decoder_for("german")
+                .decode("fu\u{308}nf0")
+                .unwrap()
+                .first
+                .value
+        );
+        assert_eq!(
+            20,
+            decoder_for("french")
+                .decode("deuxze\u{301}ro")
+                .unwrap()
+                .value()
+        );
+        assert_eq!(None, decoder_for("english").decode("eins"));
+    }
+
+    #[test]
+    fn decoder_reports_offsets_into_the_original_line() {
+        let german = decoder_for("german");
+        let line = "Äx\u{1F600}FÜNFzwei";
+        let calibration = german.decode(line).unwrap();
+        assert_eq!(
+            "FÜNF",
+            &line[calibration.first.start..calibration.first.end]
+        );
+        assert_eq!("zwei", &line[calibration.last.start..calibration.last.end]);
+        let line = "fu\u{308}nf0";
+        let calibration = german.decode(line).unwrap();
+        assert_eq!(
+            DigitMatch {
+                value: 5,
+                start: 0,
+                end: 6,
+            },
+            calibration.first
+        );
+        assert_eq!(
+            DigitMatch {
+                value: 0,
+                start: 6,
+                end: 7,
+            },
+            calibration.last
+        );
+    }
+
+    #[test]
+    fn decoder_only_matches_whole_graphemes() {
+        let french = decoder_for("french");
+        assert_eq!(11, french.decode("1cinq\u{331}").unwrap().value());
+        assert_eq!(55, french.decode("cinq").unwrap().value());
+    }
+
+    #[test]
+    fn part2_reads_a_custom_vocabulary() {
+        let vocabulary = "uno = 1\ndos = 2".parse::<Vocabulary>().unwrap();
+        assert_eq!(
+            Ok(23),
+            part2_with_vocabulary("unoxdos\n1\n".to_string(), &vocabulary, Mode::Strict)
+        );
+    }
+
+    #[test]
+    fn classifies_lines() {
+        let decoder = words_decoder();
+        assert!(matches!(decoder.classify("1abc2"), LineResult::Valid(_)));
+        assert!(matches!(
+            decoder.classify("treb7uchet"),
+            LineResult::SingleDigit(_)
+        ));
+        assert!(matches!(decoder.classify("oneight"), LineResult::Valid(_)));
+        assert_eq!(LineResult::NoDigits, decoder.classify("abc"));
+        assert_eq!(LineResult::NoDigits, decoder.classify(""));
+    }
+
+    #[test]
+    fn reports_offending_lines() {
+        let input = "1abc2\n\ntreb7uchet\nxyz\n";
+        let report = CalibrationDecoder::new(&DIGITS).report(input);
+        assert_eq!(
+            CalibrationReport {
+                no_digits: vec![2, 4],
+                single_digit: vec![3],
+            },
+            report
+        );
+        assert_eq!(
+            "Lines with no digits: 2, 4\nLines with a single digit: 3\n",
+            report.to_string()
+        );
+        assert!(CalibrationDecoder::new(&DIGITS).report("1abc2").is_empty());
+    }
+
+    #[test]
+    fn strict_mode_fails_and_lenient_mode_skips() {
+        let input = "1abc2\n\ntreb7uchet\n".to_string();
+        assert_eq!(
+            Err(CalibrationReport {
+                no_digits: vec![2],
+                single_digit: vec![],
+            }),
+            part1_with_mode(input.clone(), Mode::Strict)
+        );
+        assert_eq!(Ok(89), part1_with_mode(input.clone(), Mode::Lenient));
+        assert_eq!(
+            Err(CalibrationReport {
+                no_digits: vec![2],
+                single_digit: vec![],
+            }),
+            part2(input)
+        );
+        assert_eq!(Ok(77), part1("treb7uchet\n".to_string()));
+    }
+
+    #[test]
+    fn part1_should_handle_example() {
+        assert_eq!(
+            Ok(142),
+            part1(
+                "1abc2
+        pqr3stu8vwx
+        a1b2c3d4e5f
+        treb7uchet"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn part2_should_handle_example() {
+        assert_eq!(
+            Ok(281),
+            part2(
+                "two1nine
+        eightwothree
+        abcone2threexyz
+        xtwone3four
+        4nineeightseven2
+        zoneight234
+        7pqrstsixteen"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -12,112 +12,53 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs::read_to_string;
+use std::env::args;
+use std::process::exit;
 
-#[cfg(not(tarpaulin_include))]
-fn main() {
-    let input = read_to_string("input.txt").expect("Unable to read input file");
-    println!("Part 1: {}", part1(input.clone()));
-    println!("Part 2: {}", part2(input));
-}
+use day_01::vocabulary::{Vocabulary, BUILTIN_NAMES};
+use day_01::{
+    part1_with_mode, part2_with_vocabulary, CalibrationDecoder, CalibrationReport, Mode, DIGITS,
+};
 
-fn part1(input: String) -> usize {
-    input
-        .lines()
-        .map(|line| {
-            let numbers: Vec<char> = line.chars().filter(|char| char.is_numeric()).collect();
-            let mut number = String::new();
-            number.push(*numbers.iter().next().unwrap());
-            number.push(*numbers.iter().last().unwrap());
-            number.parse::<usize>().unwrap()
-        })
-        .sum()
-}
-
-fn part2(input: String) -> usize {
-    let numbers = vec![
-        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
-    ];
-    input
-        .lines()
-        .map(|line| {
-            let mut number = String::new();
-            let haystack = line.trim().to_lowercase();
-            let haystack_chars: Vec<char> = haystack.chars().collect();
-            for index in 0..haystack_chars.len() {
-                if haystack_chars[index].is_numeric() {
-                    number.push(haystack_chars[index]);
-                    break;
-                } else {
-                    let mut found_number = false;
-                    for (number_index, number_name) in numbers.iter().enumerate() {
-                        if haystack[index..].starts_with(number_name) {
-                            number.push_str(&(number_index + 1).to_string());
-                            found_number = true;
-                            break;
-                        }
-                    }
-                    if found_number {
-                        break;
-                    }
-                }
-            }
-            for index in (0..haystack_chars.len()).rev() {
-                if haystack_chars[index].is_numeric() {
-                    number.push(haystack_chars[index]);
-                    break;
-                } else {
-                    let mut found_number = false;
-                    for (number_index, number_name) in numbers.iter().enumerate() {
-                        if haystack[..=index].ends_with(number_name) {
-                            number.push_str(&(number_index + 1).to_string());
-                            found_number = true;
-                            break;
-                        }
-                    }
-                    if found_number {
-                        break;
-                    }
-                }
-            }
-            number.parse::<usize>().unwrap()
-        })
-        .sum()
+/// Prints the report of why a strict run failed and exits.
+#[cfg(not(tarpaulin_include))]
+fn fail(report: CalibrationReport) -> usize {
+    eprint!("{}", report);
+    exit(1);
 }
 
 #[cfg(not(tarpaulin_include))]
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_should_handle_example() {
-        assert_eq!(
-            142,
-            part1(
-                "1abc2
-        pqr3stu8vwx
-        a1b2c3d4e5f
-        treb7uchet"
-                    .to_string()
-            )
-        );
-    }
-
-    #[test]
-    fn part2_should_handle_example() {
-        assert_eq!(
-            281,
-            part2(
-                "two1nine
-        eightwothree
-        abcone2threexyz
-        xtwone3four
-        4nineeightseven2
-        zoneight234
-        7pqrstsixteen"
-                    .to_string()
-            )
-        );
+fn main() {
+    let name = args()
+        .find_map(|arg| arg.strip_prefix("--vocabulary=").map(str::to_string))
+        .unwrap_or("english".to_string());
+    let vocabulary = Vocabulary::builtin(&name).unwrap_or_else(|| {
+        panic!(
+            "Unable to find vocabulary {}; expected one of {}",
+            name,
+            BUILTIN_NAMES.join(", ")
+        )
+    });
+    let mode = if args().any(|arg| "--lenient" == arg) {
+        Mode::Lenient
+    } else {
+        Mode::Strict
+    };
+    let input = input::read(&[]);
+    if Mode::Lenient == mode {
+        let decoders = [
+            ("Part 1", CalibrationDecoder::new(&DIGITS)),
+            ("Part 2", CalibrationDecoder::from_vocabulary(&vocabulary)),
+        ];
+        for (part, decoder) in decoders {
+            let report = decoder.report(&input);
+            if !report.is_empty() {
+                eprint!("{} report:\n{}", part, report);
+            }
+        }
     }
+    let part1 = part1_with_mode(input.clone(), mode).unwrap_or_else(fail);
+    let part2 = part2_with_vocabulary(input, &vocabulary, mode).unwrap_or_else(fail);
+    println!("Part 1: {}", part1);
+    println!("Part 2: {}", part2);
 }
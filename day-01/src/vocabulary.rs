@@ -0,0 +1,166 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Word lists that the calibration decoder can read alongside the digits.
+
+use std::str::FromStr;
+
+use crate::DIGIT_WORDS;
+
+/// The names accepted by [`Vocabulary::builtin`].
+pub const BUILTIN_NAMES: [&str; 5] = ["digits", "english", "english-extended", "german", "french"];
+
+const ENGLISH_EXTENDED: [(&str, usize); 20] = [
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+    ("ten", 10),
+    ("eleven", 11),
+    ("twelve", 12),
+    ("thirteen", 13),
+    ("fourteen", 14),
+    ("fifteen", 15),
+    ("sixteen", 16),
+    ("seventeen", 17),
+    ("eighteen", 18),
+    ("nineteen", 19),
+];
+
+const GERMAN: [(&str, usize); 13] = [
+    ("null", 0),
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+    ("zehn", 10),
+    ("elf", 11),
+    ("zwölf", 12),
+];
+
+const FRENCH: [(&str, usize); 17] = [
+    ("zéro", 0),
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+    ("dix", 10),
+    ("onze", 11),
+    ("douze", 12),
+    ("treize", 13),
+    ("quatorze", 14),
+    ("quinze", 15),
+    ("seize", 16),
+];
+
+/// Words that stand for numbers, read in addition to the digits `0`-`9`.
+/// Values past 9 contribute their leading digit when first in a line and
+/// their trailing digit when last, so `fifteen` reads as 1 or 5.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Vocabulary {
+    pub words: Vec<(String, usize)>,
+}
+
+impl Vocabulary {
+    /// Looks up one of the [`BUILTIN_NAMES`]. `english` is the puzzle's own
+    /// `one` through `nine`; `digits` has no words at all.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let words: &[(&str, usize)] = match name {
+            "digits" => &[],
+            "english" => &DIGIT_WORDS,
+            "english-extended" => &ENGLISH_EXTENDED,
+            "german" => &GERMAN,
+            "french" => &FRENCH,
+            _ => return None,
+        };
+        Some(Self {
+            words: words
+                .iter()
+                .map(|(word, value)| (word.to_string(), *value))
+                .collect(),
+        })
+    }
+}
+
+/// Parses one `word = value` pair per line. Blank lines and lines starting
+/// with `#` are skipped.
+impl FromStr for Vocabulary {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut words = Vec::new();
+        for line in input.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (word, value) = line
+                .split_once('=')
+                .ok_or(format!("Unable to find '=' in {:?}", line))?;
+            let word = word.trim();
+            if word.is_empty() {
+                return Err(format!("Unable to find a word in {:?}", line));
+            }
+            let value = value
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| format!("Unable to parse value in {:?}", line))?;
+            words.push((word.to_string(), value));
+        }
+        Ok(Self { words })
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_builtin_name_resolves() {
+        for name in BUILTIN_NAMES {
+            assert!(Vocabulary::builtin(name).is_some(), "{}", name);
+        }
+        assert_eq!(None, Vocabulary::builtin("klingon"));
+        assert_eq!(9, Vocabulary::builtin("english").unwrap().words.len());
+    }
+
+    #[test]
+    fn parses_word_value_pairs() {
+        assert_eq!(
+            Ok(Vocabulary {
+                words: vec![("uno".to_string(), 1), ("dieciséis".to_string(), 16)],
+            }),
+            "# spanish\nuno = 1\n\n  dieciséis=16\n".parse::<Vocabulary>()
+        );
+        assert!("uno 1".parse::<Vocabulary>().is_err());
+        assert!(" = 1".parse::<Vocabulary>().is_err());
+        assert!("uno = one".parse::<Vocabulary>().is_err());
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Questions about a whole record of games rather than one at a time.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::{Colour, Draw, Game};
+
+/// The smallest bag that makes every game possible.
+pub fn minimal_bag(games: &[Game]) -> Draw {
+    let mut bag = Draw::default();
+    for game in games {
+        for (colour, count) in game.fewest().cubes {
+            let limit = bag.cubes.entry(colour).or_insert(0);
+            *limit = count.max(*limit);
+        }
+    }
+    bag
+}
+
+/// Raising one colour's limit in a bag, and how many more games it admits.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Raise {
+    pub colour: String,
+    pub from: u32,
+    pub to: u32,
+    pub admitted: usize,
+}
+
+/// The single colour whose limit, raised far enough, admits the most games
+/// that `bag` rules out. Ties go to the colour that sorts first. `None` if
+/// no single raise admits anything.
+pub fn best_single_raise(games: &[Game], bag: &Draw) -> Option<Raise> {
+    let mut raises: BTreeMap<Colour, Raise> = BTreeMap::new();
+    for game in games.iter().filter(|game| !game.is_possible(bag)) {
+        let fewest = game.fewest();
+        let mut over = fewest
+            .cubes
+            .iter()
+            .filter(|(colour, count)| **count > bag.count(colour));
+        let (colour, count) = match (over.next(), over.next()) {
+            (Some(only), None) => only,
+            _ => continue,
+        };
+        let raise = raises.entry(colour.clone()).or_insert(Raise {
+            colour: colour.to_string(),
+            from: bag.count(colour),
+            to: 0,
+            admitted: 0,
+        });
+        raise.to = raise.to.max(*count);
+        raise.admitted += 1;
+    }
+    raises
+        .into_values()
+        .fold(None, |best: Option<Raise>, raise| match best {
+            Some(best) if best.admitted >= raise.admitted => Some(best),
+            _ => Some(raise),
+        })
+}
+
+/// How game powers are spread. Quartiles use the nearest rank.
+#[derive(Debug, PartialEq, Clone, Default, Serialize)]
+pub struct PowerStats {
+    pub count: usize,
+    pub min: u32,
+    pub lower_quartile: u32,
+    pub median: u32,
+    pub upper_quartile: u32,
+    pub max: u32,
+    pub mean: f64,
+}
+
+/// Summarises the power of every game over `colours`.
+pub fn power_stats(games: &[Game], colours: &[&str]) -> PowerStats {
+    let mut powers = games
+        .iter()
+        .map(|game| game.power(colours))
+        .collect::<Vec<u32>>();
+    if powers.is_empty() {
+        return PowerStats::default();
+    }
+    powers.sort();
+    let rank = |quantile: usize| powers[(powers.len() * quantile).div_ceil(4).max(1) - 1];
+    PowerStats {
+        count: powers.len(),
+        min: powers[0],
+        lower_quartile: rank(1),
+        median: rank(2),
+        upper_quartile: rank(3),
+        max: powers[powers.len() - 1],
+        mean: powers.iter().map(|power| *power as f64).sum::<f64>() / powers.len() as f64,
+    }
+}
+
+/// Everything the `analyze` command reports.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct Analysis {
+    pub games: usize,
+    pub possible: usize,
+    pub bag: BTreeMap<String, u32>,
+    pub minimal_bag: BTreeMap<String, u32>,
+    pub best_raise: Option<Raise>,
+    pub powers: PowerStats,
+}
+
+fn named(draw: &Draw) -> BTreeMap<String, u32> {
+    draw.cubes
+        .iter()
+        .map(|(colour, count)| (colour.to_string(), *count))
+        .collect()
+}
+
+impl Analysis {
+    /// Analyses games against `bag`, with powers over `colours`.
+    pub fn new(games: &[Game], colours: &[&str], bag: &Draw) -> Self {
+        Self {
+            games: games.len(),
+            possible: games.iter().filter(|game| game.is_possible(bag)).count(),
+            bag: named(bag),
+            minimal_bag: named(&minimal_bag(games)),
+            best_raise: best_single_raise(games, bag),
+            powers: power_stats(games, colours),
+        }
+    }
+
+    /// The analysis as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Unable to serialize analysis")
+    }
+}
+
+impl std::fmt::Display for Analysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let bag = |bag: &BTreeMap<String, u32>| {
+            bag.iter()
+                .map(|(colour, count)| format!("{}={}", colour, count))
+                .collect::<Vec<String>>()
+                .join(",")
+        };
+        writeln!(f, "{:<16} {}", "games", self.games)?;
+        writeln!(f, "{:<16} {}", "possible", self.possible)?;
+        writeln!(f, "{:<16} {}", "bag", bag(&self.bag))?;
+        writeln!(f, "{:<16} {}", "minimal bag", bag(&self.minimal_bag))?;
+        match &self.best_raise {
+            Some(raise) => writeln!(
+                f,
+                "{:<16} {} {} -> {} admits {}",
+                "best raise", raise.colour, raise.from, raise.to, raise.admitted
+            )?,
+            None => writeln!(f, "{:<16} none", "best raise")?,
+        }
+        writeln!(f, "{:<16} {}", "power min", self.powers.min)?;
+        writeln!(f, "{:<16} {}", "power q1", self.powers.lower_quartile)?;
+        writeln!(f, "{:<16} {}", "power median", self.powers.median)?;
+        writeln!(f, "{:<16} {}", "power q3", self.powers.upper_quartile)?;
+        writeln!(f, "{:<16} {}", "power max", self.powers.max)?;
+        writeln!(f, "{:<16} {:.2}", "power mean", self.powers.mean)
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_bag, parse_games, part1_limits};
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+        ";
+
+    #[test]
+    fn finds_minimal_bag() {
+        let (_, games) = parse_games(EXAMPLE);
+        assert_eq!(
+            parse_bag("red=20,green=13,blue=15").unwrap(),
+            minimal_bag(&games)
+        );
+        assert!(games
+            .iter()
+            .all(|game| game.is_possible(&minimal_bag(&games))));
+    }
+
+    #[test]
+    fn finds_best_single_raise() {
+        let (_, games) = parse_games(EXAMPLE);
+        assert_eq!(
+            Some(Raise {
+                colour: "red".to_string(),
+                from: 12,
+                to: 20,
+                admitted: 1,
+            }),
+            best_single_raise(&games, &part1_limits())
+        );
+        assert_eq!(None, best_single_raise(&games, &minimal_bag(&games)));
+        let (_, games) = parse_games(
+            "Game 1: 14 red, 15 blue
+            Game 2: 14 red, 15 blue
+            Game 3: 16 blue",
+        );
+        assert_eq!(
+            Some(Raise {
+                colour: "blue".to_string(),
+                from: 14,
+                to: 16,
+                admitted: 1,
+            }),
+            best_single_raise(&games, &part1_limits())
+        );
+    }
+
+    #[test]
+    fn summarises_powers() {
+        let (colours, games) = parse_games(EXAMPLE);
+        let stats = power_stats(&games, &colours.names());
+        assert_eq!(
+            PowerStats {
+                count: 5,
+                min: 12,
+                lower_quartile: 36,
+                median: 48,
+                upper_quartile: 630,
+                max: 1560,
+                mean: 457.2,
+            },
+            stats
+        );
+        assert_eq!(PowerStats::default(), power_stats(&[], &[]));
+    }
+
+    #[test]
+    fn prints_table_and_json() {
+        let (colours, games) = parse_games(EXAMPLE);
+        let analysis = Analysis::new(&games, &colours.names(), &part1_limits());
+        let table = analysis.to_string();
+        assert!(table.contains("possible         3\n"));
+        assert!(table.contains("minimal bag      blue=15,green=13,red=20\n"));
+        assert!(table.contains("best raise       red 12 -> 20 admits 1\n"));
+        assert!(table.contains("power mean       457.20\n"));
+        let json: serde_json::Value = serde_json::from_str(&analysis.to_json()).unwrap();
+        assert_eq!(5, json["games"]);
+        assert_eq!(20, json["minimal_bag"]["red"]);
+        assert_eq!("red", json["best_raise"]["colour"]);
+        assert_eq!(48, json["powers"]["median"]);
+    }
+}
@@ -0,0 +1,502 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advent of Code 2023 day 2: Cube Conundrum
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+
+pub mod analysis;
+
+/// A colour name shared by every draw that shows it.
+pub type Colour = Rc<str>;
+
+/// Interns colour names as they are parsed, so any colour in the input is
+/// kept and each name is stored once.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Colours {
+    names: BTreeSet<Colour>,
+}
+
+impl Colours {
+    /// The shared name for a colour, adding it if it's new.
+    pub fn intern(&mut self, name: &str) -> Colour {
+        match self.names.get(name) {
+            Some(colour) => colour.clone(),
+            None => {
+                let colour: Colour = name.into();
+                self.names.insert(colour.clone());
+                colour
+            }
+        }
+    }
+
+    /// Every colour seen so far, sorted by name.
+    pub fn names(&self) -> Vec<&str> {
+        self.names.iter().map(|colour| &**colour).collect()
+    }
+
+    /// Parses a round like `3 blue, 4 red`.
+    pub fn parse_draw(&mut self, input: &str) -> Draw {
+        let mut draw = Draw::default();
+        for cubes in input.trim().split(", ") {
+            let mut cubes = cubes.split(" ");
+            let count = cubes
+                .next()
+                .unwrap()
+                .parse::<u32>()
+                .expect("Unable to parse cube count");
+            let colour = self.intern(cubes.next().expect("Unable to find cube colour"));
+            *draw.cubes.entry(colour).or_insert(0) += count;
+        }
+        draw
+    }
+
+    /// Parses a round like `3 blue, 4 red`, failing on anything a draw can't
+    /// hold exactly, such as a colour shown twice.
+    pub fn try_parse_draw(&mut self, input: &str) -> Result<Draw, String> {
+        let mut draw = Draw::default();
+        for cubes in input.trim().split(',') {
+            let (count, colour) = match cubes.split_whitespace().collect::<Vec<&str>>()[..] {
+                [count, colour] => (count, colour),
+                _ => return Err(format!("Unable to parse cubes {:?}", cubes.trim())),
+            };
+            let count = count
+                .parse::<u32>()
+                .map_err(|_| format!("Unable to parse cube count {:?}", count))?;
+            if draw.cubes.insert(self.intern(colour), count).is_some() {
+                return Err(format!(
+                    "{} appears more than once in {:?}",
+                    colour,
+                    input.trim()
+                ));
+            }
+        }
+        Ok(draw)
+    }
+
+    /// Parses a `Game N: ...` line into its rounds, failing on anything a
+    /// game can't hold exactly.
+    pub fn try_parse_game(&mut self, input: &str) -> Result<Game, String> {
+        let input = input.trim();
+        let (header, rounds) = input
+            .split_once(':')
+            .ok_or(format!("Unable to find ':' in {:?}", input))?;
+        let id = match header.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["Game", id] => id.parse::<u32>().ok(),
+            _ => None,
+        }
+        .ok_or(format!("Unable to parse game header {:?}", header))?;
+        let rounds = rounds
+            .split(';')
+            .map(|round| self.try_parse_draw(round))
+            .collect::<Result<Vec<Draw>, String>>()?;
+        Ok(Game { id, rounds })
+    }
+
+    /// Parses a `Game N: ...` line into its rounds.
+    pub fn parse_game(&mut self, input: &str) -> Game {
+        let input = input.trim();
+        let mut parts = input.split(": ");
+        let id = parts
+            .next()
+            .unwrap()
+            .split(" ")
+            .nth(1)
+            .unwrap()
+            .parse::<u32>()
+            .expect("Unable to parse game id");
+        let rounds = parts
+            .next()
+            .expect("Unable to find rounds")
+            .split("; ")
+            .map(|round| self.parse_draw(round))
+            .collect();
+        Game { id, rounds }
+    }
+}
+
+/// The cubes shown in one round, by colour.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Draw {
+    pub cubes: BTreeMap<Colour, u32>,
+}
+
+impl Draw {
+    /// How many cubes of a colour were shown, 0 if none.
+    pub fn count(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    /// The total number of cubes shown.
+    pub fn total(&self) -> u32 {
+        self.cubes.values().sum()
+    }
+
+    /// Whether any colour shows more cubes than `limits` allows.
+    pub fn exceeds(&self, limits: &Draw) -> bool {
+        self.cubes
+            .iter()
+            .any(|(colour, count)| *count > limits.count(colour))
+    }
+}
+
+impl std::fmt::Display for Draw {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let cubes = self
+            .cubes
+            .iter()
+            .map(|(colour, count)| format!("{} {}", count, colour))
+            .collect::<Vec<String>>();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+/// A game and every round drawn in it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Draw>,
+}
+
+impl Game {
+    /// The most cubes of a colour shown in any round.
+    pub fn max(&self, colour: &str) -> u32 {
+        self.rounds
+            .iter()
+            .map(|round| round.count(colour))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The fewest cubes of a colour shown in a round that shows it at all.
+    pub fn min(&self, colour: &str) -> Option<u32> {
+        self.rounds
+            .iter()
+            .filter_map(|round| round.cubes.get(colour).copied())
+            .min()
+    }
+
+    /// Every colour shown in the game.
+    pub fn colours(&self) -> Vec<&str> {
+        let mut colours = self
+            .rounds
+            .iter()
+            .flat_map(|round| round.cubes.keys().map(|colour| &**colour))
+            .collect::<Vec<&str>>();
+        colours.sort();
+        colours.dedup();
+        colours
+    }
+
+    /// The total number of cubes shown across every round.
+    pub fn total_cubes(&self) -> u32 {
+        self.rounds.iter().map(Draw::total).sum()
+    }
+
+    /// The index of the first round that shows more cubes than `limits`.
+    pub fn first_exceeding(&self, limits: &Draw) -> Option<usize> {
+        self.rounds.iter().position(|round| round.exceeds(limits))
+    }
+
+    /// Whether every round fits within `limits`.
+    pub fn is_possible(&self, limits: &Draw) -> bool {
+        self.first_exceeding(limits).is_none()
+    }
+
+    /// The fewest cubes of each colour that make the game possible.
+    pub fn fewest(&self) -> Draw {
+        Draw {
+            cubes: self
+                .colours()
+                .into_iter()
+                .map(|colour| (colour.into(), self.max(colour)))
+                .collect(),
+        }
+    }
+
+    /// The product of the fewest cubes of each colour that make the game
+    /// possible, 0 if it never shows one of them.
+    pub fn power(&self, colours: &[&str]) -> u32 {
+        colours.iter().map(|colour| self.max(colour)).product()
+    }
+}
+
+impl std::fmt::Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let rounds = self
+            .rounds
+            .iter()
+            .map(|round| round.to_string())
+            .collect::<Vec<String>>();
+        write!(f, "Game {}: {}", self.id, rounds.join("; "))
+    }
+}
+
+/// Parses a round like `3 blue, 4 red` with its own colours.
+pub fn parse_draw(input: &str) -> Draw {
+    Colours::default().parse_draw(input)
+}
+
+/// Parses a `Game N: ...` line with its own colours.
+pub fn parse_game(input: &str) -> Game {
+    Colours::default().parse_game(input)
+}
+
+/// Parses every game, interning their colours together.
+pub fn parse_games(input: &str) -> (Colours, Vec<Game>) {
+    let mut colours = Colours::default();
+    let games = input
+        .trim()
+        .lines()
+        .map(|line| colours.parse_game(line))
+        .collect();
+    (colours, games)
+}
+
+/// Parses a bag like `red=12,green=13,blue=14`. Pairs may also be split
+/// across lines, so the same format works in a file. Colours not in the bag
+/// can't be drawn at all.
+pub fn parse_bag(input: &str) -> Result<Draw, String> {
+    let mut bag = Draw::default();
+    for pair in input
+        .split([',', '\n'])
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+    {
+        let (colour, count) = pair
+            .split_once('=')
+            .ok_or(format!("Unable to find '=' in {:?}", pair))?;
+        let count = count
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| format!("Unable to parse count in {:?}", pair))?;
+        bag.cubes.insert(colour.trim().into(), count);
+    }
+    Ok(bag)
+}
+
+/// The bag from part 1: 12 red, 13 green and 14 blue cubes.
+pub fn part1_limits() -> Draw {
+    parse_bag("red=12,green=13,blue=14").unwrap()
+}
+
+/// Rewrites the games in puzzle format, one per line, skipping blank lines.
+/// Fails on any line a game can't hold exactly rather than dropping it.
+pub fn format_input(input: &str) -> Result<String, String> {
+    let mut colours = Colours::default();
+    let mut formatted = String::new();
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let game = colours
+            .try_parse_game(line)
+            .map_err(|error| format!("line {}: {}", index + 1, error))?;
+        formatted.push_str(&format!("{}\n", game));
+    }
+    Ok(formatted)
+}
+
+/// Sums the IDs of games possible with 12 red, 13 green and 14 blue cubes.
+pub fn part1(input: String) -> u32 {
+    part1_with_bag(input, &part1_limits())
+}
+
+/// Sums the IDs of games possible with the cubes in `bag`.
+pub fn part1_with_bag(input: String, bag: &Draw) -> u32 {
+    parse_games(&input)
+        .1
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+/// Sums the power of the fewest cubes, over every colour in the input, that
+/// make each game possible.
+pub fn part2(input: String) -> u32 {
+    let (colours, games) = parse_games(&input);
+    let colours = colours.names();
+    games.iter().map(|game| game.power(&colours)).sum()
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAME_3: &str = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+
+    #[test]
+    fn parses_game() {
+        let game = parse_game(GAME_3);
+        assert_eq!(3, game.id);
+        assert_eq!(3, game.rounds.len());
+        assert_eq!(
+            Draw {
+                cubes: BTreeMap::from([("green".into(), 5), ("red".into(), 1)]),
+            },
+            game.rounds[2]
+        );
+    }
+
+    #[test]
+    fn game_answers_queries() {
+        let game = parse_game(GAME_3);
+        assert_eq!(20, game.max("red"));
+        assert_eq!(13, game.max("green"));
+        assert_eq!(0, game.max("purple"));
+        assert_eq!(Some(1), game.min("red"));
+        assert_eq!(Some(5), game.min("blue"));
+        assert_eq!(None, game.min("purple"));
+        assert_eq!(vec!["blue", "green", "red"], game.colours());
+        assert_eq!(62, game.total_cubes());
+        assert_eq!(Some(0), game.first_exceeding(&part1_limits()));
+        assert_eq!(
+            None,
+            game.first_exceeding(&parse_draw("20 red, 13 green, 6 blue"))
+        );
+        assert_eq!(
+            Some(1),
+            game.first_exceeding(&parse_draw("20 red, 8 green, 6 blue"))
+        );
+        assert_eq!(parse_draw("6 blue, 13 green, 20 red"), game.fewest());
+        assert_eq!(1560, game.power(&["blue", "green", "red"]));
+        assert_eq!(0, game.power(&["blue", "green", "red", "yellow"]));
+    }
+
+    #[test]
+    fn interns_colours_once() {
+        let mut colours = Colours::default();
+        let first = colours.parse_draw("1 yellow, 2 red");
+        let second = colours.parse_draw("3 yellow");
+        let (first, _) = first.cubes.get_key_value("yellow").unwrap();
+        let (second, _) = second.cubes.get_key_value("yellow").unwrap();
+        assert!(Rc::ptr_eq(first, second));
+        assert_eq!(vec!["red", "yellow"], colours.names());
+    }
+
+    #[test]
+    fn parses_bags() {
+        assert_eq!(
+            Ok(parse_draw("12 red, 13 green, 14 blue, 3 yellow")),
+            parse_bag("red=12,green=13,blue=14,yellow=3")
+        );
+        assert_eq!(
+            Ok(parse_draw("12 red, 3 yellow")),
+            parse_bag("red = 12\nyellow=3\n")
+        );
+        assert!(parse_bag("red:12").is_err());
+        assert!(parse_bag("red=many").is_err());
+    }
+
+    #[test]
+    fn handles_extra_colours() {
+        let input = "Game 1: 1 red, 2 yellow; 1 green, 1 blue
+        Game 2: 3 red, 1 green, 1 blue, 1 yellow
+        "
+        .to_string();
+        assert_eq!(0, part1(input.clone()));
+        assert_eq!(
+            3,
+            part1_with_bag(
+                input.clone(),
+                &parse_bag("red=12,green=13,blue=14,yellow=3").unwrap()
+            )
+        );
+        assert_eq!(
+            2,
+            part1_with_bag(
+                input.clone(),
+                &parse_bag("red=3,green=1,blue=1,yellow=1").unwrap()
+            )
+        );
+        assert_eq!(2 + 3, part2(input));
+    }
+
+    #[test]
+    fn game_can_print() {
+        let game = parse_game(GAME_3);
+        assert_eq!(
+            "Game 3: 6 blue, 8 green, 20 red; 5 blue, 13 green, 4 red; 5 green, 1 red",
+            game.to_string()
+        );
+        assert_eq!(game, parse_game(&game.to_string()));
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            8,
+            part1(
+                "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+        "
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            2286,
+            part2(
+                "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+                Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+                Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+                Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+                Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+                "
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn formats_input() {
+        assert_eq!(
+            Ok("Game 1: 3 blue, 4 red; 2 green\nGame 2: 1 red\n".to_string()),
+            format_input("  Game 1: 4 red, 3 blue; 2 green\n\tGame 2: 1 red\n\n")
+        );
+    }
+
+    #[test]
+    fn formatting_round_trips() {
+        let input = format!("  {}\n\t{}\n\n", GAME_3, "Game 4: 1 green, 3 red; 14 red");
+        let formatted = format_input(&input).unwrap();
+        assert_eq!(parse_games(&input).1, parse_games(&formatted).1);
+        let mut colours = Colours::default();
+        assert_eq!(
+            colours.try_parse_game(
+                "Game 3:8 green,  6 blue, 20 red;5 blue, 4 red, 13 green; 5 green, 1 red"
+            ),
+            colours.try_parse_game(formatted.lines().next().unwrap())
+        );
+        assert_eq!(Ok(formatted.clone()), format_input(&formatted));
+        assert_eq!(
+            Err("line 2: red appears more than once in \"3 red, 2 red, 1 blue\"".to_string()),
+            format_input("Game 1: 1 red\nGame 2: 3 red, 2 red, 1 blue\n")
+        );
+        assert!(format_input("Game 1: 3 red blue").is_err());
+        assert!(format_input("Game 1: three red").is_err());
+        assert!(format_input("Game one: 3 red").is_err());
+        assert!(format_input("Game 1 3 red").is_err());
+        assert!(format_input("Game 1: 3 red;").is_err());
+    }
+}
@@ -12,146 +12,62 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::env::args;
 use std::fs::read_to_string;
+use std::process::exit;
+
+use day_02::analysis::Analysis;
+use day_02::{format_input, parse_bag, parse_games, part1_limits, part1_with_bag, part2};
 
 #[cfg(not(tarpaulin_include))]
 fn main() {
-    let input = read_to_string("input.txt").expect("Unable to read input file");
-    println!("Part 1: {}", part1(input.clone()));
-    println!("Part 2: {}", part2(input));
-}
-
-#[derive(Debug, PartialEq)]
-struct Part1Game {
-    id: u32,
-    max_green: u32,
-    max_red: u32,
-    max_blue: u32,
-}
-
-fn parse_part1_game(input: &str) -> Part1Game {
-    let input = input.trim();
-    let mut parts = input.split(": ");
-    let id = parts
-        .next()
-        .unwrap()
-        .split(" ")
-        .nth(1)
-        .unwrap()
-        .parse::<u32>()
-        .unwrap();
-    let games = parts.next().unwrap();
-    let games = games.split("; ");
-    let mut max_green = 0;
-    let mut max_red = 0;
-    let mut max_blue = 0;
-    for game in games {
-        let colors = game.split(", ");
-        for color in colors {
-            let mut color = color.split(" ");
-            let count = color.next().unwrap().parse::<u32>().unwrap();
-            let color = color.next().unwrap();
-            match color {
-                "green" => {
-                    if count > max_green {
-                        max_green = count;
-                    }
-                }
-                "red" => {
-                    if count > max_red {
-                        max_red = count;
-                    }
-                }
-                "blue" => {
-                    if count > max_blue {
-                        max_blue = count;
-                    }
-                }
-                _ => {}
+    let mut bag = part1_limits();
+    let mut analyze = false;
+    let mut json = false;
+    let mut fmt = false;
+    let mut args = args().skip(1).peekable();
+    if Some("analyze") == args.peek().map(String::as_str) {
+        analyze = true;
+        args.next();
+    }
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bag" => {
+                let spec = args.next().expect("Unable to find bag after --bag");
+                bag = parse_bag(&spec).expect("Unable to parse bag");
+            }
+            "--bag-file" => {
+                let file = args.next().expect("Unable to find file after --bag-file");
+                let spec = read_to_string(file).expect("Unable to read bag file");
+                bag = parse_bag(&spec).expect("Unable to parse bag");
             }
+            "--json" => json = true,
+            "--fmt" => fmt = true,
+            _ => {}
         }
     }
-    Part1Game {
-        id,
-        max_green,
-        max_red,
-        max_blue,
-    }
-}
-
-fn part1(input: String) -> u32 {
-    input
-        .trim()
-        .lines()
-        .map(parse_part1_game)
-        .map(|game| {
-            if game.max_green <= 13 && game.max_red <= 12 && game.max_blue <= 14 {
-                game.id
-            } else {
-                0
+    let skip = if analyze { 2 } else { 1 };
+    let input = input::read_from(std::env::args().skip(skip), &["--bag", "--bag-file"]);
+    if fmt {
+        match format_input(&input) {
+            Ok(formatted) => print!("{}", formatted),
+            Err(error) => {
+                eprintln!("{}", error);
+                exit(1);
             }
-        })
-        .sum()
-}
-
-fn part2(input: String) -> u32 {
-    input
-        .trim()
-        .lines()
-        .map(parse_part1_game)
-        .map(|game| game.max_green * game.max_red * game.max_blue)
-        .sum()
-}
-
-#[cfg(not(tarpaulin_include))]
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn parses_part1_game() {
-        assert_eq!(
-            Part1Game {
-                id: 3,
-                max_green: 13,
-                max_red: 20,
-                max_blue: 6,
-            },
-            parse_part1_game(
-                "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
-            )
-        );
-    }
-
-    #[test]
-    fn solves_part1() {
-        assert_eq!(
-            8,
-            part1(
-                "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
-        "
-                .to_string()
-            )
-        );
+        }
+        return;
     }
-
-    #[test]
-    fn solves_part2() {
-        assert_eq!(
-            2286,
-            part2(
-                "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-                Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-                Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-                Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-                Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
-                "
-                .to_string()
-            )
-        );
+    if analyze {
+        let (colours, games) = parse_games(&input);
+        let analysis = Analysis::new(&games, &colours.names(), &bag);
+        if json {
+            println!("{}", analysis.to_json());
+        } else {
+            print!("{}", analysis);
+        }
+        return;
     }
+    println!("Part 1: {}", part1_with_bag(input.clone(), &bag));
+    println!("Part 2: {}", part2(input));
 }
@@ -0,0 +1,396 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advent of Code 2023 day 3: Gear Ratios
+
+use std::collections::HashMap;
+
+pub mod rules;
+
+use rules::Rules;
+
+/// A number in the schematic and the columns it covers, inclusive.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct NumberSpan {
+    pub row: usize,
+    pub x_min: usize,
+    pub x_max: usize,
+    pub value: u64,
+}
+
+/// A character the rules count as a symbol.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Symbol {
+    pub row: usize,
+    pub column: usize,
+    pub symbol: char,
+}
+
+/// Every number and symbol in a schematic, with which of them touch,
+/// including diagonally. Numbers and symbols are referred to by their
+/// index, in reading order.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Schematic {
+    pub numbers: Vec<NumberSpan>,
+    pub symbols: Vec<Symbol>,
+    number_symbols: Vec<Vec<usize>>,
+    symbol_numbers: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    /// The indices of the symbols touching a number.
+    pub fn symbols_of(&self, number: usize) -> &[usize] {
+        &self.number_symbols[number]
+    }
+
+    /// The indices of the numbers touching a symbol.
+    pub fn numbers_of(&self, symbol: usize) -> &[usize] {
+        &self.symbol_numbers[symbol]
+    }
+
+    /// The numbers touching at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &NumberSpan> {
+        self.numbers
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.number_symbols[*index].is_empty())
+            .map(|(_, number)| number)
+    }
+
+    /// The numbers touching no symbol at all.
+    pub fn loose_numbers(&self) -> impl Iterator<Item = &NumberSpan> {
+        self.numbers
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.number_symbols[*index].is_empty())
+            .map(|(_, number)| number)
+    }
+
+    /// The indices of the symbols that are gears under `rules`.
+    pub fn gears<'a>(&'a self, rules: &'a Rules) -> impl Iterator<Item = usize> + 'a {
+        (0..self.symbols.len()).filter(|index| {
+            rules.gears.contains(&self.symbols[*index].symbol)
+                && rules.neighbours.admits(self.numbers_of(*index).len())
+        })
+    }
+
+    /// The numbers touching a gear, combined under `rules`, or None if
+    /// that overflows.
+    pub fn gear_ratio(&self, gear: usize, rules: &Rules) -> Option<u64> {
+        rules.combine.apply(
+            self.numbers_of(gear)
+                .iter()
+                .map(|number| self.numbers[*number].value),
+        )
+    }
+}
+
+/// Finds the numbers and symbols in one row, in column order. A number is
+/// closed by any non-digit or by the end of the row, so rows of any length
+/// scan the same way.
+pub fn scan_row(row: usize, line: &str, rules: &Rules) -> (Vec<NumberSpan>, Vec<Symbol>) {
+    let mut numbers = Vec::new();
+    let mut symbols = Vec::new();
+    let mut current: Option<NumberSpan> = None;
+    for (column, character) in line.chars().enumerate() {
+        if let Some(digit) = character.to_digit(10) {
+            let number = current.get_or_insert(NumberSpan {
+                row,
+                x_min: column,
+                x_max: column,
+                value: 0,
+            });
+            number.x_max = column;
+            number.value = number.value * 10 + digit as u64;
+            continue;
+        }
+        numbers.extend(current.take());
+        if rules.symbols.contains(character) {
+            symbols.push(Symbol {
+                row,
+                column,
+                symbol: character,
+            });
+        }
+    }
+    numbers.extend(current);
+    (numbers, symbols)
+}
+
+/// Locates every number and symbol in a schematic, under the puzzle's
+/// rules, and links each number to the symbols around it.
+pub fn parse_schematic(input: &str) -> Schematic {
+    parse_schematic_with_rules(input, &Rules::default())
+}
+
+/// Like [`parse_schematic`], with symbols picked out by `rules`.
+pub fn parse_schematic_with_rules(input: &str, rules: &Rules) -> Schematic {
+    let mut schematic = Schematic::default();
+    let mut positions: HashMap<(usize, usize), usize> = HashMap::new();
+    for (row, line) in input.trim().lines().enumerate() {
+        let (numbers, symbols) = scan_row(row, line.trim(), rules);
+        for symbol in symbols {
+            positions.insert((row, symbol.column), schematic.symbols.len());
+            schematic.symbols.push(symbol);
+        }
+        schematic.numbers.extend(numbers);
+    }
+    schematic.symbol_numbers = vec![Vec::new(); schematic.symbols.len()];
+    for (index, number) in schematic.numbers.iter().enumerate() {
+        let mut symbols = Vec::new();
+        for row in number.row.saturating_sub(1)..=number.row + 1 {
+            for column in number.x_min.saturating_sub(1)..=number.x_max + 1 {
+                if let Some(symbol) = positions.get(&(row, column)) {
+                    symbols.push(*symbol);
+                    schematic.symbol_numbers[*symbol].push(index);
+                }
+            }
+        }
+        schematic.number_symbols.push(symbols);
+    }
+    schematic
+}
+
+/// Sums every number adjacent to a symbol.
+pub fn part1(input: String) -> Result<u64, String> {
+    part1_with_rules(input, &Rules::default())
+}
+
+/// Sums every number adjacent to a symbol under `rules`, or errors if the
+/// sum overflows.
+pub fn part1_with_rules(input: String, rules: &Rules) -> Result<u64, String> {
+    parse_schematic_with_rules(&input, rules)
+        .part_numbers()
+        .try_fold(0_u64, |total, number| {
+            total.checked_add(number.value).ok_or(format!(
+                "Part numbers overflowed at row {}, column {}",
+                number.row + 1,
+                number.x_min + 1
+            ))
+        })
+}
+
+/// Sums the gear ratios of every `*` next to exactly two numbers.
+pub fn part2(input: String) -> Result<u64, String> {
+    part2_with_rules(input, &Rules::default())
+}
+
+/// Sums the gear ratios of every gear under `rules`, or errors if a ratio
+/// or the sum overflows.
+pub fn part2_with_rules(input: String, rules: &Rules) -> Result<u64, String> {
+    let schematic = parse_schematic_with_rules(&input, rules);
+    let sum = schematic.gears(rules).try_fold(0_u64, |total, gear| {
+        let symbol = schematic.symbols[gear];
+        schematic
+            .gear_ratio(gear, rules)
+            .and_then(|ratio| total.checked_add(ratio))
+            .ok_or(format!(
+                "Gear ratios overflowed at row {}, column {}",
+                symbol.row + 1,
+                symbol.column + 1
+            ))
+    });
+    sum
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......
+        .....+.58.
+        ..592.....
+        ......755.
+        ...$.*....
+        .664.598..
+        ";
+
+    #[test]
+    fn parses_schematic() {
+        let schematic = parse_schematic(EXAMPLE);
+        assert_eq!(10, schematic.numbers.len());
+        assert_eq!(6, schematic.symbols.len());
+        assert_eq!(
+            NumberSpan {
+                row: 0,
+                x_min: 5,
+                x_max: 7,
+                value: 114,
+            },
+            schematic.numbers[1]
+        );
+        assert_eq!(
+            Symbol {
+                row: 3,
+                column: 6,
+                symbol: '#',
+            },
+            schematic.symbols[1]
+        );
+        assert_eq!(
+            NumberSpan {
+                row: 0,
+                x_min: 1,
+                x_max: 2,
+                value: 42,
+            },
+            parse_schematic(".42").numbers[0]
+        );
+    }
+
+    fn spans(line: &str) -> Vec<(usize, usize, u64)> {
+        scan_row(0, line, &Rules::default())
+            .0
+            .iter()
+            .map(|number| (number.x_min, number.x_max, number.value))
+            .collect()
+    }
+
+    #[test]
+    fn scans_numbers_at_row_edges() {
+        assert_eq!(vec![(0, 2, 467), (5, 7, 114)], spans("467..114"));
+        assert_eq!(vec![(3, 5, 633)], spans("...633"));
+        assert_eq!(vec![(0, 4, 12345)], spans("12345"));
+        assert_eq!(vec![(0, 0, 7)], spans("7"));
+        assert_eq!(vec![(0, 1, 12), (3, 4, 34)], spans("12*34"));
+        assert_eq!(Vec::<(usize, usize, u64)>::new(), spans(""));
+        assert_eq!(
+            vec![Symbol {
+                row: 0,
+                column: 2,
+                symbol: '*',
+            }],
+            scan_row(0, "12*34", &Rules::default()).1
+        );
+    }
+
+    #[test]
+    fn handles_edge_numbers_in_both_parts() {
+        assert_eq!(Ok(12 * 34), part2("..12\n...*\n..34".to_string()));
+        assert_eq!(Ok(12 + 34), part1("..12\n...*\n..34".to_string()));
+        assert_eq!(Ok(10 * 20), part2("10*20".to_string()));
+    }
+
+    #[test]
+    fn handles_single_column_grids() {
+        assert_eq!(Ok(4 * 5), part2("4\n*\n5".to_string()));
+        assert_eq!(Ok(9), part1("4\n*\n5\n.\n6".to_string()));
+    }
+
+    #[test]
+    fn handles_ragged_rows() {
+        let schematic = parse_schematic("1\n.*\n...23\n9");
+        assert_eq!(
+            vec![(0, 0, 1), (2, 3, 23), (3, 0, 9)],
+            schematic
+                .numbers
+                .iter()
+                .map(|number| (number.row, number.x_min, number.value))
+                .collect::<Vec<(usize, usize, u64)>>()
+        );
+        assert_eq!(Ok(1), part1("1\n.*\n...23\n9".to_string()));
+        assert_eq!(Ok(23), part2("1\n.*\n..23".to_string()));
+    }
+
+    #[test]
+    fn links_numbers_and_symbols() {
+        let schematic = parse_schematic(EXAMPLE);
+        assert_eq!(&[0], schematic.symbols_of(0));
+        assert!(schematic.symbols_of(1).is_empty());
+        assert_eq!(&[0, 2], schematic.numbers_of(0));
+        assert_eq!(
+            vec![114, 58],
+            schematic
+                .loose_numbers()
+                .map(|number| number.value)
+                .collect::<Vec<u64>>()
+        );
+        let rules = Rules::default();
+        assert_eq!(vec![0, 5], schematic.gears(&rules).collect::<Vec<usize>>());
+        assert_eq!(Some(16345), schematic.gear_ratio(0, &rules));
+    }
+
+    #[test]
+    fn solves_variants_with_rules() {
+        let only_stars = "symbols = only *".parse::<Rules>().unwrap();
+        assert_eq!(
+            Ok(467 + 35 + 617 + 755 + 598),
+            part1_with_rules(EXAMPLE.to_string(), &only_stars)
+        );
+        let lonely_gears = "neighbours = at-least 1\ncombine = sum"
+            .parse::<Rules>()
+            .unwrap();
+        assert_eq!(
+            Ok(467 + 35 + 617 + 755 + 598),
+            part2_with_rules(EXAMPLE.to_string(), &lonely_gears)
+        );
+        let every_symbol = "gears = *#+$\nneighbours = exactly 1\ncombine = max"
+            .parse::<Rules>()
+            .unwrap();
+        assert_eq!(
+            Ok(617 + 633 + 592 + 664),
+            part2_with_rules(EXAMPLE.to_string(), &every_symbol)
+        );
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(Ok(4361), part1(EXAMPLE.to_string()));
+        assert_eq!(
+            Ok(44),
+            part1(
+                "$..
+                .11
+                .11
+                $..
+                ..$
+                11.
+                11.
+                ..$"
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(Ok(467835), part2(EXAMPLE.to_string()));
+    }
+
+    #[test]
+    fn errors_on_overflow() {
+        assert_eq!(
+            Ok(9999999999 + 9999999999),
+            part1("9999999999*9999999999".to_string())
+        );
+        assert_eq!(
+            Err("Gear ratios overflowed at row 1, column 11".to_string()),
+            part2("9999999999*9999999999".to_string())
+        );
+        assert_eq!(
+            Err("Part numbers overflowed at row 2, column 1".to_string()),
+            part1("18446744073709551615\n1*".to_string())
+        );
+        let sums = "combine = sum".parse::<Rules>().unwrap();
+        assert_eq!(
+            Err("Gear ratios overflowed at row 1, column 21".to_string()),
+            part2_with_rules("18446744073709551615*1".to_string(), &sums)
+        );
+    }
+}
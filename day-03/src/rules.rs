@@ -0,0 +1,239 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! What counts as a symbol or a gear, and how gear values combine.
+
+use std::str::FromStr;
+
+/// Which characters are symbols. Digits never are.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SymbolClass {
+    /// Every character except these
+    AllExcept(Vec<char>),
+    /// Only these characters
+    Only(Vec<char>),
+}
+
+impl SymbolClass {
+    /// Whether a character is a symbol.
+    pub fn contains(&self, character: char) -> bool {
+        !character.is_ascii_digit()
+            && match self {
+                SymbolClass::AllExcept(blanks) => !blanks.contains(&character),
+                SymbolClass::Only(symbols) => symbols.contains(&character),
+            }
+    }
+}
+
+/// How many numbers a gear must touch.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Neighbours {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+impl Neighbours {
+    /// Whether a symbol touching `count` numbers qualifies.
+    pub fn admits(&self, count: usize) -> bool {
+        match self {
+            Neighbours::Exactly(expected) => *expected == count,
+            Neighbours::AtLeast(minimum) => *minimum <= count,
+        }
+    }
+}
+
+/// How the numbers touching a gear combine into its ratio.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Combine {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Combine {
+    /// Combines the values, 0 if there are none, or None if the result
+    /// overflows.
+    pub fn apply(&self, mut values: impl Iterator<Item = u64>) -> Option<u64> {
+        let first = match values.next() {
+            Some(first) => first,
+            None => return Some(0),
+        };
+        match self {
+            Combine::Product => values.try_fold(first, u64::checked_mul),
+            Combine::Sum => values.try_fold(first, u64::checked_add),
+            Combine::Max => Some(values.fold(first, u64::max)),
+        }
+    }
+}
+
+/// The rules a schematic is read by. The default is the puzzle's: anything
+/// but `.` is a symbol, and a `*` touching exactly two numbers is a gear
+/// whose ratio is their product.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Rules {
+    pub symbols: SymbolClass,
+    pub gears: Vec<char>,
+    pub neighbours: Neighbours,
+    pub combine: Combine,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            symbols: SymbolClass::AllExcept(vec!['.']),
+            gears: vec!['*'],
+            neighbours: Neighbours::Exactly(2),
+            combine: Combine::Product,
+        }
+    }
+}
+
+fn parse_count(value: &str) -> Result<usize, String> {
+    value
+        .trim()
+        .parse::<usize>()
+        .map_err(|_| format!("Unable to parse neighbour count {:?}", value))
+}
+
+/// Parses one `key = value` rule per line, keeping the default for any
+/// key left out. Blank lines and lines starting with `#` are skipped.
+///
+/// ```text
+/// symbols = all-except .
+/// gears = *
+/// neighbours = at-least 2
+/// combine = sum
+/// ```
+///
+/// `symbols` may also be `only` followed by the symbol characters. Every gear
+/// must be a symbol.
+impl FromStr for Rules {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut rules = Rules::default();
+        for line in input.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("Unable to find '=' in {:?}", line))?;
+            let value = value.trim();
+            let chars = |characters: &str| {
+                characters
+                    .chars()
+                    .filter(|character| !character.is_whitespace())
+                    .collect::<Vec<char>>()
+            };
+            match key.trim() {
+                "symbols" => {
+                    rules.symbols = if let Some(blanks) = value.strip_prefix("all-except") {
+                        SymbolClass::AllExcept(chars(blanks))
+                    } else if let Some(symbols) = value.strip_prefix("only") {
+                        SymbolClass::Only(chars(symbols))
+                    } else {
+                        return Err(format!("Unable to parse symbols {:?}", value));
+                    }
+                }
+                "gears" => rules.gears = chars(value),
+                "neighbours" => {
+                    rules.neighbours = if let Some(count) = value.strip_prefix("exactly") {
+                        Neighbours::Exactly(parse_count(count)?)
+                    } else if let Some(count) = value.strip_prefix("at-least") {
+                        Neighbours::AtLeast(parse_count(count)?)
+                    } else {
+                        return Err(format!("Unable to parse neighbours {:?}", value));
+                    }
+                }
+                "combine" => {
+                    rules.combine = match value {
+                        "product" => Combine::Product,
+                        "sum" => Combine::Sum,
+                        "max" => Combine::Max,
+                        _ => return Err(format!("Unable to parse combine {:?}", value)),
+                    }
+                }
+                _ => return Err(format!("Unknown rule {:?}", key.trim())),
+            }
+        }
+        if let Some(gear) = rules
+            .gears
+            .iter()
+            .find(|gear| !rules.symbols.contains(**gear))
+        {
+            return Err(format!("Gear {:?} is not a symbol", gear));
+        }
+        Ok(rules)
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_symbols() {
+        let puzzle = Rules::default().symbols;
+        assert!(puzzle.contains('*'));
+        assert!(puzzle.contains('#'));
+        assert!(!puzzle.contains('.'));
+        assert!(!puzzle.contains('7'));
+        let only = SymbolClass::Only(vec!['*', '#']);
+        assert!(only.contains('#'));
+        assert!(!only.contains('$'));
+    }
+
+    #[test]
+    fn combines_values() {
+        let values = [2, 3, 4];
+        assert_eq!(Some(24), Combine::Product.apply(values.into_iter()));
+        assert_eq!(Some(9), Combine::Sum.apply(values.into_iter()));
+        assert_eq!(Some(4), Combine::Max.apply(values.into_iter()));
+        assert_eq!(Some(0), Combine::Product.apply([].into_iter()));
+        let large = [u64::MAX, 2];
+        assert_eq!(None, Combine::Product.apply(large.into_iter()));
+        assert_eq!(None, Combine::Sum.apply(large.into_iter()));
+        assert_eq!(Some(u64::MAX), Combine::Max.apply(large.into_iter()));
+        assert!(Neighbours::AtLeast(2).admits(3));
+        assert!(!Neighbours::Exactly(2).admits(3));
+    }
+
+    #[test]
+    fn parses_rules() {
+        assert_eq!(Ok(Rules::default()), "".parse::<Rules>());
+        assert_eq!(
+            Ok(Rules {
+                symbols: SymbolClass::Only(vec!['*', '#']),
+                gears: vec!['*', '#'],
+                neighbours: Neighbours::AtLeast(1),
+                combine: Combine::Max,
+            }),
+            "# variant\nsymbols = only * #\ngears = *#\nneighbours = at-least 1\ncombine = max\n"
+                .parse::<Rules>()
+        );
+        assert!("symbols = some".parse::<Rules>().is_err());
+        assert!("neighbours = exactly two".parse::<Rules>().is_err());
+        assert!("combine = mean".parse::<Rules>().is_err());
+        assert!("colour = red".parse::<Rules>().is_err());
+        assert!("gears".parse::<Rules>().is_err());
+        assert_eq!(
+            Err("Gear '*' is not a symbol".to_string()),
+            "symbols = only #".parse::<Rules>()
+        );
+        assert!("gears = *.".parse::<Rules>().is_err());
+        assert!("gears = 7".parse::<Rules>().is_err());
+    }
+}
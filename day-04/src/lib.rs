@@ -0,0 +1,628 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advent of Code 2023 day 4: Scratchcards
+
+use std::collections::{BTreeMap, HashSet};
+
+pub mod scoring;
+
+use scoring::{CopyCascade, Doubling, ScoringRule};
+
+/// A scratchcard with its winning and available numbers, both as sets and
+/// in the order they were listed.
+#[derive(Debug, PartialEq)]
+pub struct Card {
+    pub id: u32,
+    pub winning: HashSet<u32>,
+    pub available: HashSet<u32>,
+    pub winning_list: Vec<u32>,
+    pub available_list: Vec<u32>,
+    pub intersection: usize,
+}
+
+impl Card {
+    /// Builds a card from its number lists in their original order.
+    pub fn new(id: u32, winning_list: Vec<u32>, available_list: Vec<u32>) -> Self {
+        let winning = winning_list.iter().copied().collect::<HashSet<u32>>();
+        let available = available_list.iter().copied().collect::<HashSet<u32>>();
+        Self {
+            id,
+            intersection: winning.intersection(&available).count(),
+            winning,
+            available,
+            winning_list,
+            available_list,
+        }
+    }
+}
+
+impl std::fmt::Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Card {}:", self.id)?;
+        for number in &self.winning_list {
+            write!(f, " {:>2}", number)?;
+        }
+        write!(f, " |")?;
+        for number in &self.available_list {
+            write!(f, " {:>2}", number)?;
+        }
+        Ok(())
+    }
+}
+
+/// Which of a card's number lists a diagnostic is about.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NumberList {
+    Winning,
+    Available,
+}
+
+/// A problem found while reading a card.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Problem {
+    /// The line doesn't start with `Card N:`
+    MissingId,
+    /// There's no `|` between the winning and available numbers
+    MissingSeparator,
+    /// A token that should be a number isn't
+    NonNumeric { token: String },
+    /// A number is listed more than once in the same list
+    DuplicateNumber { list: NumberList, number: u32 },
+    /// The card's ID doesn't follow the previous card's
+    NonSequentialId { expected: u32, found: u32 },
+    /// The previous card's ID is the largest there is, so none can follow it
+    NoNextId { previous: u32 },
+}
+
+/// A problem and the 1-based line it was found on.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub problem: Problem,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.problem {
+            Problem::MissingId => write!(f, "missing `Card N:` header"),
+            Problem::MissingSeparator => write!(f, "missing `|` separator"),
+            Problem::NonNumeric { token } => write!(f, "{:?} is not a number", token),
+            Problem::DuplicateNumber { list, number } => write!(
+                f,
+                "{} appears more than once in the {} numbers",
+                number,
+                match list {
+                    NumberList::Winning => "winning",
+                    NumberList::Available => "available",
+                }
+            ),
+            Problem::NonSequentialId { expected, found } => {
+                write!(f, "expected card {} but found card {}", expected, found)
+            }
+            Problem::NoNextId { previous } => {
+                write!(f, "no card can follow card {}", previous)
+            }
+        }
+    }
+}
+
+fn parse_numbers(input: &str, list: NumberList, problems: &mut Vec<Problem>) -> Vec<u32> {
+    let mut numbers = Vec::new();
+    for token in input.split_whitespace() {
+        match token.parse::<u32>() {
+            Ok(number) => {
+                if numbers.contains(&number)
+                    && !problems.contains(&Problem::DuplicateNumber { list, number })
+                {
+                    problems.push(Problem::DuplicateNumber { list, number });
+                }
+                numbers.push(number);
+            }
+            Err(_) => problems.push(Problem::NonNumeric {
+                token: token.to_string(),
+            }),
+        }
+    }
+    numbers
+}
+
+/// Reads the ID from a line's `Card N:` header, whatever follows it.
+fn card_id(input: &str) -> Option<u32> {
+    let (header, _) = input.trim().split_once(':')?;
+    match header.split_whitespace().collect::<Vec<&str>>()[..] {
+        ["Card", id] => id.parse::<u32>().ok(),
+        _ => None,
+    }
+}
+
+/// Parses a `Card N: ... | ...` line, reporting everything wrong with it.
+/// Duplicate numbers still give a card, since the sets simply drop them;
+/// any other problem means there's no card.
+pub fn check_card(input: &str) -> (Option<Card>, Vec<Problem>) {
+    let mut problems = Vec::new();
+    let (_, numbers) = input.trim().split_once(':').unwrap_or(("", input));
+    let id = card_id(input);
+    if id.is_none() {
+        problems.push(Problem::MissingId);
+    }
+    let (winning, available) = match numbers.split_once('|') {
+        Some((winning, available)) => (winning.trim(), available.trim()),
+        None => {
+            problems.push(Problem::MissingSeparator);
+            (numbers, "")
+        }
+    };
+    let winning = parse_numbers(winning, NumberList::Winning, &mut problems);
+    let available = parse_numbers(available, NumberList::Available, &mut problems);
+    let usable = problems
+        .iter()
+        .all(|problem| matches!(problem, Problem::DuplicateNumber { .. }));
+    match id {
+        Some(id) if usable => (Some(Card::new(id, winning, available)), problems),
+        _ => (None, problems),
+    }
+}
+
+/// Checks every card, including that the IDs count up by one from the
+/// first card's. IDs are read from the headers, so a card whose numbers
+/// can't be read still counts. Returns the cards that could be read and
+/// every problem.
+pub fn check_cards(input: &str) -> (Vec<Card>, Vec<Diagnostic>) {
+    let mut cards: Vec<Card> = Vec::new();
+    let mut diagnostics = Vec::new();
+    let mut previous: Option<u32> = None;
+    for (index, line) in input.trim().lines().enumerate() {
+        let (card, problems) = check_card(line);
+        let mut problems = problems;
+        let id = card_id(line);
+        if let (Some(id), Some(previous)) = (id, previous) {
+            match previous.checked_add(1) {
+                Some(expected) if expected != id => {
+                    problems.push(Problem::NonSequentialId {
+                        expected,
+                        found: id,
+                    });
+                }
+                Some(_) => {}
+                None => problems.push(Problem::NoNextId { previous }),
+            }
+        }
+        previous = id.or(previous);
+        diagnostics.extend(problems.into_iter().map(|problem| Diagnostic {
+            line: index + 1,
+            problem,
+        }));
+        cards.extend(card);
+    }
+    (cards, diagnostics)
+}
+
+/// Parses a `Card N: ... | ...` line.
+pub fn parse_card(input: &str) -> Card {
+    match check_card(input) {
+        (Some(card), _) => card,
+        (None, problems) => panic!("Unable to parse card {:?}: {:?}", input.trim(), problems),
+    }
+}
+
+/// Rewrites the cards in puzzle format, one per line, skipping blank lines.
+/// Fails on the first line that doesn't give a card rather than dropping it.
+pub fn format_input(input: &str) -> Result<String, String> {
+    let mut formatted = String::new();
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match check_card(line) {
+            (Some(card), _) => formatted.push_str(&format!("{}\n", card)),
+            (None, problems) => {
+                let diagnostic = Diagnostic {
+                    line: index + 1,
+                    problem: problems[0].clone(),
+                };
+                return Err(diagnostic.to_string());
+            }
+        }
+    }
+    Ok(formatted)
+}
+
+/// Sums the points of every card.
+pub fn part1(input: String) -> u128 {
+    let cards = input.trim().lines().map(parse_card).collect::<Vec<Card>>();
+    Doubling
+        .score(&cards)
+        .unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// What to do with copies won past the last card.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Overflow {
+    /// Drop them, as the puzzle promises never happens
+    #[default]
+    Clamp,
+    /// Stop with [`SimulationError::OutOfRange`]
+    Error,
+    /// Wrap around to the first cards. Those have already been scored, so
+    /// the copies add to their counts without winning anything themselves.
+    Wrap,
+}
+
+/// Why a simulation couldn't finish.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SimulationError {
+    /// Two cards share an ID
+    DuplicateId(u32),
+    /// A card won copies past the last card under [`Overflow::Error`]
+    OutOfRange { id: u32, matches: usize },
+    /// A card's copy count no longer fits in a u128
+    CountOverflow(u32),
+}
+
+impl std::fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SimulationError::DuplicateId(id) => write!(f, "Card {} appears more than once", id),
+            SimulationError::OutOfRange { id, matches } => {
+                write!(f, "Card {} wins {} copies past the last card", id, matches)
+            }
+            SimulationError::CountOverflow(id) => {
+                write!(f, "Card {} has too many copies to count", id)
+            }
+        }
+    }
+}
+
+/// How many copies of each card are held once every win is processed.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Simulation {
+    pub copies: BTreeMap<u32, u128>,
+}
+
+impl Simulation {
+    /// The copies held of a card, `None` if there's no such card.
+    pub fn copies_of(&self, id: u32) -> Option<u128> {
+        self.copies.get(&id).copied()
+    }
+
+    /// The total number of cards held.
+    pub fn total(&self) -> u128 {
+        self.copies.values().sum()
+    }
+}
+
+/// Plays out the cards in ID order, whatever order they're given in. A card
+/// with N matches wins a copy of each of the next N cards by ID, so gaps in
+/// the IDs are skipped rather than counted.
+pub fn simulate(cards: &[Card], overflow: Overflow) -> Result<Simulation, SimulationError> {
+    let mut matches = BTreeMap::new();
+    for card in cards {
+        if matches.insert(card.id, card.intersection).is_some() {
+            return Err(SimulationError::DuplicateId(card.id));
+        }
+    }
+    let ids = matches.keys().copied().collect::<Vec<u32>>();
+    let mut counts = vec![1_u128; ids.len()];
+    for (index, id) in ids.iter().enumerate() {
+        let wins = matches[id];
+        if index + wins >= ids.len() && Overflow::Error == overflow {
+            return Err(SimulationError::OutOfRange {
+                id: *id,
+                matches: wins,
+            });
+        }
+        for offset in 1..=wins {
+            let target = match overflow {
+                Overflow::Wrap => (index + offset) % ids.len(),
+                _ if index + offset < ids.len() => index + offset,
+                _ => break,
+            };
+            counts[target] = counts[target]
+                .checked_add(counts[index])
+                .ok_or(SimulationError::CountOverflow(ids[target]))?;
+        }
+    }
+    Ok(Simulation {
+        copies: ids.into_iter().zip(counts).collect(),
+    })
+}
+
+/// Counts the cards held once every won copy has been processed.
+pub fn part2(input: String) -> u128 {
+    let cards = input.trim().lines().map(parse_card).collect::<Vec<Card>>();
+    CopyCascade {
+        overflow: Overflow::Clamp,
+    }
+    .score(&cards)
+    .unwrap_or_else(|error| panic!("{}", error))
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_card() {
+        assert_eq!(
+            Card {
+                id: 1,
+                winning: vec![41, 48, 83, 86, 17].into_iter().collect(),
+                available: vec![83, 86, 6, 31, 17, 9, 48, 53].into_iter().collect(),
+                winning_list: vec![41, 48, 83, 86, 17],
+                available_list: vec![83, 86, 6, 31, 17, 9, 48, 53],
+                intersection: 4
+            },
+            parse_card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")
+        );
+    }
+
+    #[test]
+    fn card_can_print() {
+        let card = parse_card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53");
+        assert_eq!(
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+            card.to_string()
+        );
+        assert_eq!(card, parse_card(&card.to_string()));
+    }
+
+    /// A card holding `matches` of its winning numbers plus one that isn't.
+    pub(crate) fn card(id: u32, matches: usize) -> Card {
+        let winning = (0..matches as u32).collect::<Vec<u32>>();
+        let mut held = winning.clone();
+        held.push(matches as u32);
+        Card::new(id, winning, held)
+    }
+
+    #[test]
+    fn keeps_duplicates_in_order() {
+        let (card, problems) = check_card("Card 2: 17 4 17 | 17 5 5 5");
+        let card = card.unwrap();
+        assert_eq!(vec![17, 4, 17], card.winning_list);
+        assert_eq!(vec![17, 5, 5, 5], card.available_list);
+        assert_eq!(2, card.winning.len());
+        assert_eq!(1, card.intersection);
+        assert_eq!(
+            vec![
+                Problem::DuplicateNumber {
+                    list: NumberList::Winning,
+                    number: 17,
+                },
+                Problem::DuplicateNumber {
+                    list: NumberList::Available,
+                    number: 5,
+                },
+            ],
+            problems
+        );
+    }
+
+    #[test]
+    fn reports_malformed_cards() {
+        assert_eq!(
+            (None, vec![Problem::MissingSeparator]),
+            check_card("Card 1: 41 48 83 86 17")
+        );
+        assert_eq!(
+            (None, vec![Problem::MissingId]),
+            check_card("Crd 1: 41 | 83")
+        );
+        assert_eq!(
+            (
+                None,
+                vec![Problem::NonNumeric {
+                    token: "x".to_string(),
+                }]
+            ),
+            check_card("Card 1: 41 x | 83")
+        );
+        assert_eq!(
+            (Some(Card::new(1, vec![41], vec![83])), Vec::new()),
+            check_card("Card 1: 41|83")
+        );
+    }
+
+    #[test]
+    fn reports_diagnostics_by_line() {
+        let (cards, diagnostics) = check_cards(
+            "Card 1: 41 48 | 83 86
+            Card 2: 41 41 | 83 86
+            Card 4: 41 48 | 83 86
+            Card 5: 41 48 83 86",
+        );
+        assert_eq!(
+            vec![1, 2, 4],
+            cards.iter().map(|card| card.id).collect::<Vec<u32>>()
+        );
+        assert_eq!(
+            vec![
+                "line 2: 41 appears more than once in the winning numbers",
+                "line 3: expected card 3 but found card 4",
+                "line 4: missing `|` separator",
+            ],
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.to_string())
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn sequences_ids_through_unusable_cards() {
+        let (cards, diagnostics) = check_cards(
+            "Card 1: 41 48 | 83 86
+            Card 2: 41 48 83 86
+            Card 3: 41 48 | 83 86
+            Card 4 41 48 | 83 86
+            Card 6: 41 48 | 83 86",
+        );
+        assert_eq!(
+            vec![1, 3, 6],
+            cards.iter().map(|card| card.id).collect::<Vec<u32>>()
+        );
+        assert_eq!(
+            vec![
+                "line 2: missing `|` separator",
+                "line 4: missing `Card N:` header",
+                "line 4: \"Card\" is not a number",
+                "line 5: expected card 4 but found card 6",
+            ],
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.to_string())
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn reports_ids_past_the_largest() {
+        let (cards, diagnostics) = check_cards(
+            "Card 4294967295: 41 | 83
+            Card 1: 41 | 83",
+        );
+        assert_eq!(2, cards.len());
+        assert_eq!(
+            vec!["line 2: no card can follow card 4294967295"],
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.to_string())
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn simulates_out_of_order_and_sparse_ids() {
+        let simulation = simulate(
+            &[card(30, 0), card(10, 2), card(20, 1), card(40, 0)],
+            Overflow::Clamp,
+        )
+        .unwrap();
+        assert_eq!(Some(1), simulation.copies_of(10));
+        assert_eq!(Some(2), simulation.copies_of(20));
+        assert_eq!(Some(4), simulation.copies_of(30));
+        assert_eq!(Some(1), simulation.copies_of(40));
+        assert_eq!(None, simulation.copies_of(11));
+        assert_eq!(8, simulation.total());
+        assert_eq!(
+            Err(SimulationError::DuplicateId(10)),
+            simulate(&[card(10, 0), card(10, 0)], Overflow::Clamp)
+        );
+    }
+
+    #[test]
+    fn applies_overflow_policy() {
+        let cards = [card(1, 1), card(2, 3), card(3, 0)];
+        assert_eq!(
+            vec![1, 2, 3],
+            simulate(&cards, Overflow::Clamp)
+                .unwrap()
+                .copies
+                .into_values()
+                .collect::<Vec<u128>>()
+        );
+        assert_eq!(
+            Err(SimulationError::OutOfRange { id: 2, matches: 3 }),
+            simulate(&cards, Overflow::Error)
+        );
+        assert_eq!(
+            "Card 2 wins 3 copies past the last card",
+            simulate(&cards, Overflow::Error).unwrap_err().to_string()
+        );
+        assert_eq!(
+            vec![3, 4, 3],
+            simulate(&cards, Overflow::Wrap)
+                .unwrap()
+                .copies
+                .into_values()
+                .collect::<Vec<u128>>()
+        );
+    }
+
+    #[test]
+    fn counts_past_u64() {
+        let cards = (1..=100)
+            .map(|id| card(id, 100 - id as usize))
+            .collect::<Vec<Card>>();
+        let simulation = simulate(&cards, Overflow::Error).unwrap();
+        assert_eq!(Some(1 << 99), simulation.copies_of(100));
+        assert_eq!((1 << 100) - 1, simulation.total());
+        let cards = (1..=130)
+            .map(|id| card(id, 130 - id as usize))
+            .collect::<Vec<Card>>();
+        assert_eq!(
+            Err(SimulationError::CountOverflow(129)),
+            simulate(&cards, Overflow::Clamp)
+        );
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            13,
+            part1(
+                "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        "
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            30,
+            part2(
+                "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+                Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+                Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+                Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+                Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+                Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+                "
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn formats_input() {
+        assert_eq!(
+            Ok("Card 1: 41 48  3 | 83  6 31\nCard 2:  1 | 12\n".to_string()),
+            format_input("Card 1:41 48 3 | 83 6 31\n  Card   2: 1 | 12\n")
+        );
+    }
+
+    #[test]
+    fn formatting_round_trips() {
+        let input = "Card 1:41 48 3 48 | 83 6 31\n  Card   2: 1 | 12\n\n";
+        let formatted = format_input(input).unwrap();
+        let parse = |input: &str| input.trim().lines().map(parse_card).collect::<Vec<Card>>();
+        assert_eq!(parse(input), parse(&formatted));
+        assert_eq!(Ok(formatted.clone()), format_input(&formatted));
+        assert_eq!(
+            Err("line 2: \"4x\" is not a number".to_string()),
+            format_input("Card 1: 1 | 2\nCard 2: 4x | 2\n")
+        );
+        assert!(format_input("Card 1: 1 2").is_err());
+        assert!(format_input("Card: 1 | 2").is_err());
+    }
+}
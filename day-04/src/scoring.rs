@@ -0,0 +1,276 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ways to score a pile of scratchcards, so game variants can be compared
+//! over the same cards.
+
+use evalexpr::{build_operator_tree, context_map, Node};
+
+use crate::{simulate, Card, Overflow};
+
+/// Scores a whole pile of cards. Every rule fails with an error, rather than
+/// saturating, once a score no longer fits in a u128.
+pub trait ScoringRule {
+    /// A short name to report the score under.
+    fn name(&self) -> String;
+
+    /// The pile's score, or why it couldn't be scored.
+    fn score(&self, cards: &[Card]) -> Result<u128, String>;
+}
+
+/// Sums a per-card score, which is `None` if it doesn't fit.
+fn sum_cards(cards: &[Card], score: impl Fn(&Card) -> Option<u128>) -> Result<u128, String> {
+    cards.iter().try_fold(0_u128, |total, card| {
+        score(card)
+            .and_then(|score| total.checked_add(score))
+            .ok_or(overflowed(card))
+    })
+}
+
+fn overflowed(card: &Card) -> String {
+    format!("Score overflowed at card {}", card.id)
+}
+
+/// 1 point for the first match, doubled for each one after. The puzzle's
+/// part 1.
+pub struct Doubling;
+
+impl ScoringRule for Doubling {
+    fn name(&self) -> String {
+        "doubling".to_string()
+    }
+
+    fn score(&self, cards: &[Card]) -> Result<u128, String> {
+        sum_cards(cards, |card| match card.intersection {
+            0 => Some(0),
+            matches => u32::try_from(matches - 1)
+                .ok()
+                .and_then(|shift| 1_u128.checked_shl(shift)),
+        })
+    }
+}
+
+/// 1 point per match.
+pub struct Linear;
+
+impl ScoringRule for Linear {
+    fn name(&self) -> String {
+        "linear".to_string()
+    }
+
+    fn score(&self, cards: &[Card]) -> Result<u128, String> {
+        sum_cards(cards, |card| u128::try_from(card.intersection).ok())
+    }
+}
+
+/// The Nth Fibonacci number for N matches, so 0, 1, 1, 2, 3, 5...
+pub struct Fibonacci;
+
+impl ScoringRule for Fibonacci {
+    fn name(&self) -> String {
+        "fibonacci".to_string()
+    }
+
+    fn score(&self, cards: &[Card]) -> Result<u128, String> {
+        sum_cards(cards, |card| {
+            let (mut previous, mut current) = (1_u128, 0_u128);
+            for _ in 0..card.intersection {
+                (previous, current) = (current, previous.checked_add(current)?);
+            }
+            Some(current)
+        })
+    }
+}
+
+/// The number of cards held once every won copy is processed. The
+/// puzzle's part 2.
+pub struct CopyCascade {
+    pub overflow: Overflow,
+}
+
+impl ScoringRule for CopyCascade {
+    fn name(&self) -> String {
+        "copy-cascade".to_string()
+    }
+
+    fn score(&self, cards: &[Card]) -> Result<u128, String> {
+        simulate(cards, self.overflow)
+            .map(|simulation| simulation.total())
+            .map_err(|error| error.to_string())
+    }
+}
+
+/// A per-card score from an expression over `matches`, `id`, `winning` and
+/// `available`, e.g. `matches * matches`.
+pub struct Expression {
+    pub name: String,
+    tree: Node,
+}
+
+impl Expression {
+    /// Compiles an expression, failing early on bad syntax.
+    pub fn new(name: &str, expression: &str) -> Result<Self, String> {
+        let tree = build_operator_tree(expression)
+            .map_err(|error| format!("Unable to parse rule {}: {}", name, error))?;
+        Ok(Self {
+            name: name.to_string(),
+            tree,
+        })
+    }
+}
+
+impl ScoringRule for Expression {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn score(&self, cards: &[Card]) -> Result<u128, String> {
+        let mut total = 0_u128;
+        for card in cards {
+            let context = context_map! {
+                "matches" => card.intersection as i64,
+                "id" => card.id as i64,
+                "winning" => card.winning.len() as i64,
+                "available" => card.available.len() as i64,
+            }
+            .unwrap();
+            let score = self
+                .tree
+                .eval_int_with_context(&context)
+                .map_err(|error| format!("Unable to score card {}: {}", card.id, error))?;
+            let score = u128::try_from(score)
+                .map_err(|_| format!("Card {} scored {} under {}", card.id, score, self.name))?;
+            total = total.checked_add(score).ok_or(overflowed(card))?;
+        }
+        Ok(total)
+    }
+}
+
+/// The built-in rules, in the order they're reported.
+pub fn builtin_rules() -> Vec<Box<dyn ScoringRule>> {
+    vec![
+        Box::new(Doubling),
+        Box::new(Linear),
+        Box::new(Fibonacci),
+        Box::new(CopyCascade {
+            overflow: Overflow::Clamp,
+        }),
+    ]
+}
+
+/// Parses one `name = expression` rule per line. Blank lines and lines
+/// starting with `#` are skipped.
+pub fn parse_rules(input: &str) -> Result<Vec<Box<dyn ScoringRule>>, String> {
+    let mut rules: Vec<Box<dyn ScoringRule>> = Vec::new();
+    for line in input.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (name, expression) = line
+            .split_once('=')
+            .ok_or(format!("Unable to find '=' in {:?}", line))?;
+        rules.push(Box::new(Expression::new(name.trim(), expression.trim())?));
+    }
+    Ok(rules)
+}
+
+/// Scores the same cards under every rule.
+pub fn compare(
+    cards: &[Card],
+    rules: &[Box<dyn ScoringRule>],
+) -> Vec<(String, Result<u128, String>)> {
+    rules
+        .iter()
+        .map(|rule| (rule.name(), rule.score(cards)))
+        .collect()
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_card;
+    use crate::tests::card;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    fn cards() -> Vec<Card> {
+        EXAMPLE.lines().map(parse_card).collect()
+    }
+
+    #[test]
+    fn scores_builtin_rules() {
+        assert_eq!(
+            vec![
+                ("doubling".to_string(), Ok(13)),
+                ("linear".to_string(), Ok(4 + 2 + 2 + 1)),
+                ("fibonacci".to_string(), Ok(3 + 1 + 1 + 1)),
+                ("copy-cascade".to_string(), Ok(30)),
+            ],
+            compare(&cards(), &builtin_rules())
+        );
+    }
+
+    #[test]
+    fn scores_expression_rules() {
+        let rules = parse_rules(
+            "# variants\nsquares = matches * matches\n\nflat = if(matches > 0, 10, 0)\n",
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                ("squares".to_string(), Ok(16 + 4 + 4 + 1)),
+                ("flat".to_string(), Ok(40)),
+            ],
+            compare(&cards(), &rules)
+        );
+        let negative = parse_rules("penalty = matches - 5").unwrap();
+        assert!(negative[0].score(&cards()).is_err());
+        assert!(parse_rules("broken = (matches + 1").is_err());
+        assert!(parse_rules("no equals").is_err());
+    }
+
+    #[test]
+    fn every_rule_errors_on_overflow() {
+        assert_eq!(Ok(1 << 127), Doubling.score(&[card(1, 128)]));
+        assert_eq!(
+            Err("Score overflowed at card 1".to_string()),
+            Doubling.score(&[card(1, 129)])
+        );
+        assert_eq!(
+            Err("Score overflowed at card 2".to_string()),
+            Doubling.score(&[card(1, 128), card(2, 128)])
+        );
+        assert!(Fibonacci.score(&[card(1, 186)]).is_ok());
+        assert_eq!(
+            Err("Score overflowed at card 1".to_string()),
+            Fibonacci.score(&[card(1, 187)])
+        );
+        let cascade = (1..=130)
+            .map(|id| card(id, 130 - id as usize))
+            .collect::<Vec<Card>>();
+        assert_eq!(
+            Err("Card 129 has too many copies to count".to_string()),
+            CopyCascade {
+                overflow: Overflow::Clamp,
+            }
+            .score(&cascade)
+        );
+    }
+}
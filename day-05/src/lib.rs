@@ -0,0 +1,1115 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advent of Code 2023 day 5: If You Give A Seed A Fertilizer
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::ops::Range;
+
+pub mod lint;
+
+/// An inclusive source range and the destination its start maps to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct AocRange {
+    pub min: usize,
+    pub max: usize,
+    pub base: usize,
+}
+
+impl AocRange {
+    /// Whether a source value falls inside the range.
+    pub fn contains(&self, value: usize) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Maps a source value, or `None` if it falls outside the range.
+    pub fn get_value(&self, value: usize) -> Option<usize> {
+        if self.contains(value) {
+            Some(self.base + value - self.min)
+        } else {
+            None
+        }
+    }
+
+    /// What's left of the range once `other`'s source values are cut out,
+    /// in source order.
+    pub fn without(&self, other: &AocRange) -> Vec<AocRange> {
+        if other.max < self.min || self.max < other.min {
+            return vec![*self];
+        }
+        let mut rest = Vec::new();
+        if self.min < other.min {
+            rest.push(AocRange {
+                min: self.min,
+                max: other.min - 1,
+                base: self.base,
+            });
+        }
+        if other.max < self.max {
+            rest.push(AocRange {
+                min: other.max + 1,
+                max: self.max,
+                base: self.base + (other.max + 1 - self.min),
+            });
+        }
+        rest
+    }
+}
+
+impl std::fmt::Display for AocRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.base, self.min, self.max - self.min + 1)
+    }
+}
+
+/// One almanac map; values outside every range map to themselves.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AocMap(pub Vec<AocRange>);
+
+impl std::fmt::Display for AocMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for range in &self.0 {
+            writeln!(f, "{}", range)?;
+        }
+        Ok(())
+    }
+}
+
+impl AocMap {
+    /// Maps a source value to its destination.
+    pub fn get_value(&self, value: usize) -> usize {
+        for range in &self.0 {
+            if range.contains(value) {
+                return range.get_value(value).unwrap();
+            }
+        }
+        value
+    }
+
+    /// The ranges cut down to the values each one maps, sorted by source.
+    /// Where ranges overlap the earlier one wins, as in
+    /// [`AocMap::get_value`].
+    pub fn disjoint(&self) -> Vec<AocRange> {
+        let mut disjoint: Vec<AocRange> = Vec::new();
+        for aoc_range in &self.0 {
+            let mut rest = vec![*aoc_range];
+            for earlier in &disjoint {
+                rest = rest
+                    .iter()
+                    .flat_map(|piece| piece.without(earlier))
+                    .collect();
+            }
+            disjoint.extend(rest);
+        }
+        disjoint.sort_by_key(|aoc_range| aoc_range.min);
+        disjoint
+    }
+
+    /// Maps a whole range of source values, splitting it wherever it
+    /// crosses the edge of one of the map's ranges. The pieces come back in
+    /// source order.
+    pub fn map_range(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let sorted = self.disjoint();
+        let mut result = Vec::new();
+        let mut start = range.start;
+        for aoc_range in sorted {
+            if start >= range.end || aoc_range.min >= range.end {
+                break;
+            }
+            if aoc_range.max < start {
+                continue;
+            }
+            if start < aoc_range.min {
+                result.push(start..aoc_range.min);
+                start = aoc_range.min;
+            }
+            let end = range.end.min(aoc_range.max + 1);
+            result
+                .push(aoc_range.base + start - aoc_range.min..aoc_range.base + end - aoc_range.min);
+            start = end;
+        }
+        if start < range.end {
+            result.push(start..range.end);
+        }
+        result
+    }
+
+    /// Every value from 0 up to `usize::MAX` covered by exactly one range, in
+    /// order, with the gaps filled by ranges that map to themselves.
+    pub fn pieces(&self) -> Vec<AocRange> {
+        let sorted = self.disjoint();
+        let mut pieces = Vec::new();
+        let mut start = 0;
+        for aoc_range in sorted {
+            if start < aoc_range.min {
+                pieces.push(AocRange {
+                    min: start,
+                    max: aoc_range.min - 1,
+                    base: start,
+                });
+            }
+            start = aoc_range.max + 1;
+            pieces.push(aoc_range);
+        }
+        if start < usize::MAX {
+            pieces.push(AocRange {
+                min: start,
+                max: usize::MAX - 1,
+                base: start,
+            });
+        }
+        pieces
+    }
+
+    /// The map that applies this one and then `next`, as one sorted list of
+    /// non-overlapping ranges. Ranges that map to themselves are dropped and
+    /// neighbours that shift by the same amount are merged.
+    pub fn then(&self, next: &AocMap) -> AocMap {
+        let mut result: Vec<AocRange> = Vec::new();
+        for piece in self.pieces() {
+            let mut start = piece.min;
+            let image = piece.base..piece.base + (piece.max - piece.min + 1);
+            for output in next.map_range(image) {
+                let length = output.end - output.start;
+                let aoc_range = AocRange {
+                    min: start,
+                    max: start + length - 1,
+                    base: output.start,
+                };
+                start += length;
+                if aoc_range.min == aoc_range.base {
+                    continue;
+                }
+                match result.last_mut() {
+                    Some(last)
+                        if last.max + 1 == aoc_range.min
+                            && last.base + (last.max - last.min + 1) == aoc_range.base =>
+                    {
+                        last.max = aoc_range.max
+                    }
+                    _ => result.push(aoc_range),
+                }
+            }
+        }
+        AocMap(result)
+    }
+
+    /// Composes a chain of maps into one, e.g. seed to location.
+    pub fn compose(maps: &[AocMap]) -> AocMap {
+        maps.iter()
+            .fold(AocMap(Vec::new()), |composed, map| composed.then(map))
+    }
+
+    /// Maps a value with a binary search. Needs the ranges sorted and
+    /// non-overlapping, as [`AocMap::then`] leaves them.
+    pub fn lookup(&self, value: usize) -> usize {
+        match self.0.binary_search_by(|aoc_range| {
+            if aoc_range.max < value {
+                Ordering::Less
+            } else if aoc_range.min > value {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }) {
+            Ok(index) => self.0[index].get_value(value).unwrap(),
+            Err(_) => value,
+        }
+    }
+
+    /// The map run backwards, from destination to source, sorted by
+    /// destination. Gaps are included, so when two sources share a
+    /// destination the inverse has overlapping ranges.
+    pub fn inverse(&self) -> AocMap {
+        let mut inverse = self
+            .pieces()
+            .into_iter()
+            .map(|piece| AocRange {
+                min: piece.base,
+                max: piece.base + (piece.max - piece.min),
+                base: piece.min,
+            })
+            .collect::<Vec<AocRange>>();
+        inverse.sort_by_key(|aoc_range| aoc_range.min);
+        AocMap(inverse)
+    }
+
+    /// Every source value that maps to `value`.
+    pub fn preimage(&self, value: usize) -> Vec<usize> {
+        self.inverse()
+            .0
+            .iter()
+            .filter_map(|aoc_range| aoc_range.get_value(value))
+            .collect()
+    }
+
+    /// The seed in `seeds` with the lowest destination, and that
+    /// destination. Walks the inverse from the lowest destination up, so it
+    /// stops as soon as nothing lower can turn up.
+    pub fn lowest(&self, seeds: &[Range<usize>]) -> Option<(usize, usize)> {
+        let mut best: Option<(usize, usize)> = None;
+        for aoc_range in self.inverse().0 {
+            if best.is_some_and(|(_, location)| location <= aoc_range.min) {
+                break;
+            }
+            let sources = aoc_range.base..aoc_range.base + (aoc_range.max - aoc_range.min + 1);
+            for seed in seeds {
+                let start = seed.start.max(sources.start);
+                if start >= seed.end.min(sources.end) {
+                    continue;
+                }
+                let location = aoc_range.min + start - aoc_range.base;
+                if best.is_none_or(|(_, best)| location < best) {
+                    best = Some((start, location));
+                }
+            }
+        }
+        best
+    }
+
+    /// Maps every range, keeping the pieces in order.
+    pub fn map_ranges(&self, ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+        ranges
+            .iter()
+            .flat_map(|range| self.map_range(range.clone()))
+            .collect()
+    }
+}
+
+/// Parses the `destination source length` lines of a map. Zero-length
+/// ranges map nothing and ranges whose exclusive end doesn't fit in a usize
+/// can't be mapped, so both are skipped; see [`lint`] to find them.
+pub fn parse_to_map(input: &str) -> AocMap {
+    let input = input.trim();
+    let mut result = Vec::new();
+    for line in input.lines() {
+        if line.ends_with(':') {
+            continue;
+        }
+        let line = line.trim();
+        let parts = line.split_whitespace().collect::<Vec<&str>>();
+        let value = parts[0].parse::<usize>().unwrap();
+        let key = parts[1].parse::<usize>().unwrap();
+        let max = parts[2].parse::<usize>().unwrap();
+        if 0 == max {
+            continue;
+        }
+        if key.checked_add(max).is_none() || value.checked_add(max).is_none() {
+            continue;
+        }
+        result.push(AocRange {
+            min: key,
+            max: key + max - 1,
+            base: value,
+        });
+    }
+    AocMap(result)
+}
+
+/// Parses the `seeds:` line as individual seeds.
+pub fn parse_seeds(input: &str) -> Vec<usize> {
+    let input = input.trim().strip_prefix("seeds: ").unwrap();
+    input
+        .split_whitespace()
+        .map(|seed| seed.parse::<usize>().unwrap())
+        .collect()
+}
+
+/// Parses the `seeds:` line as `start length` pairs.
+pub fn parse_seeds_into_ranges(input: &str) -> Vec<Range<usize>> {
+    parse_seeds(input)
+        .chunks(2)
+        .map(|pair| pair[0]..pair[0] + pair[1])
+        .collect()
+}
+
+/// Parses the seven maps that follow the `seeds:` line, in order.
+pub fn parse_maps<'a>(chunks: impl Iterator<Item = &'a str>) -> Vec<AocMap> {
+    chunks.map(parse_to_map).collect()
+}
+
+/// Why an almanac can't be used.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AlmanacError {
+    /// A section doesn't start with an `X-to-Y map:` header
+    MissingHeader(String),
+    /// Two sections map the same pair of categories
+    DuplicateMap { from: String, to: String },
+    /// Following the maps leads back to this category
+    Cycle(String),
+    /// These categories can't be reached from the rest
+    Disconnected(Vec<String>),
+    /// No chain of maps leads from one category to the other
+    NoPath { from: String, to: String },
+    /// A line the parser would drop or misread, so it can't be written back
+    Unreadable(String),
+}
+
+impl std::fmt::Display for AlmanacError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AlmanacError::MissingHeader(line) => {
+                write!(f, "Expected an X-to-Y map header but found {:?}", line)
+            }
+            AlmanacError::DuplicateMap { from, to } => {
+                write!(f, "There is more than one {}-to-{} map", from, to)
+            }
+            AlmanacError::Cycle(category) => write!(f, "The maps loop back to {}", category),
+            AlmanacError::Disconnected(categories) => write!(
+                f,
+                "Unable to reach {} from the other categories",
+                categories.join(", ")
+            ),
+            AlmanacError::NoPath { from, to } => {
+                write!(f, "No chain of maps leads from {} to {}", from, to)
+            }
+            AlmanacError::Unreadable(line) => write!(f, "Unable to read {}", line),
+        }
+    }
+}
+
+/// The seeds and a graph of categories, with a map along each edge.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub maps: BTreeMap<(String, String), AocMap>,
+}
+
+impl Almanac {
+    /// Every category named by a map, sorted.
+    pub fn categories(&self) -> BTreeSet<&str> {
+        self.maps
+            .keys()
+            .flat_map(|(from, to)| [from.as_str(), to.as_str()])
+            .collect()
+    }
+
+    /// The categories `from` has maps to.
+    fn next(&self, from: &str) -> Vec<&str> {
+        self.maps
+            .keys()
+            .filter(|(source, _)| source == from)
+            .map(|(_, destination)| destination.as_str())
+            .collect()
+    }
+
+    /// Checks that the maps form one connected graph without cycles.
+    pub fn validate(&self) -> Result<(), AlmanacError> {
+        let categories = self.categories();
+        let mut done: BTreeSet<&str> = BTreeSet::new();
+        for start in &categories {
+            let mut stack = vec![(*start, 0)];
+            let mut on_path: Vec<&str> = Vec::new();
+            while let Some((category, depth)) = stack.pop() {
+                on_path.truncate(depth);
+                if on_path.contains(&category) {
+                    return Err(AlmanacError::Cycle(category.to_string()));
+                }
+                if !done.insert(category) && depth > 0 {
+                    continue;
+                }
+                on_path.push(category);
+                for next in self.next(category) {
+                    stack.push((next, depth + 1));
+                }
+            }
+        }
+        let mut reached: BTreeSet<&str> = BTreeSet::new();
+        let mut queue = categories
+            .iter()
+            .take(1)
+            .copied()
+            .collect::<VecDeque<&str>>();
+        while let Some(category) = queue.pop_front() {
+            if !reached.insert(category) {
+                continue;
+            }
+            for (from, to) in self.maps.keys() {
+                if from == category {
+                    queue.push_back(to);
+                } else if to == category {
+                    queue.push_back(from);
+                }
+            }
+        }
+        let unreached = categories
+            .difference(&reached)
+            .map(|category| category.to_string())
+            .collect::<Vec<String>>();
+        if unreached.is_empty() {
+            Ok(())
+        } else {
+            Err(AlmanacError::Disconnected(unreached))
+        }
+    }
+
+    /// The categories passed through going from `from` to `to`, both
+    /// included. Finds the shortest chain when there's more than one.
+    pub fn path(&self, from: &str, to: &str) -> Result<Vec<String>, AlmanacError> {
+        let mut previous: BTreeMap<&str, &str> = BTreeMap::new();
+        let mut queue = VecDeque::from([from]);
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                let mut path = vec![to.to_string()];
+                let mut current = to;
+                while current != from {
+                    current = previous[current];
+                    path.push(current.to_string());
+                }
+                path.reverse();
+                return Ok(path);
+            }
+            for next in self.next(category) {
+                if next != from && !previous.contains_key(next) {
+                    previous.insert(next, category);
+                    queue.push_back(next);
+                }
+            }
+        }
+        Err(AlmanacError::NoPath {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+
+    /// One map straight from `from` to `to`.
+    pub fn chain(&self, from: &str, to: &str) -> Result<AocMap, AlmanacError> {
+        let path = self.path(from, to)?;
+        let maps = path
+            .windows(2)
+            .map(|pair| self.maps[&(pair[0].clone(), pair[1].clone())].clone())
+            .collect::<Vec<AocMap>>();
+        Ok(AocMap::compose(&maps))
+    }
+
+    /// Converts a value from one category to another.
+    pub fn convert(&self, value: usize, from: &str, to: &str) -> Result<usize, AlmanacError> {
+        Ok(self.chain(from, to)?.lookup(value))
+    }
+
+    /// The `seeds:` line read as `start length` pairs.
+    pub fn seed_ranges(&self) -> Vec<Range<usize>> {
+        self.seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect()
+    }
+}
+
+/// Prints the seeds, then each map once every map into its source
+/// category has been printed, so the chain reads top to bottom.
+impl std::fmt::Display for Almanac {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let seeds = self
+            .seeds
+            .iter()
+            .map(|seed| seed.to_string())
+            .collect::<Vec<String>>();
+        writeln!(f, "seeds: {}", seeds.join(" "))?;
+        let mut remaining = self.maps.iter().collect::<Vec<_>>();
+        while !remaining.is_empty() {
+            let ready = remaining
+                .iter()
+                .position(|((from, _), _)| !remaining.iter().any(|((_, to), _)| to == from))
+                .unwrap_or(0);
+            let ((from, to), map) = remaining.remove(ready);
+            write!(f, "\n{}-to-{} map:\n{}", from, to, map)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the seeds and every `X-to-Y map:` section, whatever the
+/// categories and however many there are, then validates the graph.
+pub fn parse_almanac(input: &str) -> Result<Almanac, AlmanacError> {
+    let mut chunks = input.trim().split("\n\n");
+    let mut almanac = Almanac {
+        seeds: parse_seeds(chunks.next().unwrap()),
+        maps: BTreeMap::new(),
+    };
+    for chunk in chunks {
+        let header = chunk.trim().lines().next().unwrap_or("").trim();
+        let (from, to) = header
+            .strip_suffix(" map:")
+            .and_then(|categories| categories.split_once("-to-"))
+            .ok_or(AlmanacError::MissingHeader(header.to_string()))?;
+        let key = (from.to_string(), to.to_string());
+        if almanac.maps.contains_key(&key) {
+            return Err(AlmanacError::DuplicateMap {
+                from: key.0,
+                to: key.1,
+            });
+        }
+        almanac.maps.insert(key, parse_to_map(chunk));
+    }
+    almanac.validate()?;
+    Ok(almanac)
+}
+
+/// Rewrites the almanac in puzzle format. Fails rather than dropping
+/// anything: a bad seed, or a map line that's malformed, zero-length or
+/// overflows.
+pub fn format_input(input: &str) -> Result<String, AlmanacError> {
+    let seeds = input.trim().lines().next().unwrap_or("");
+    if !seeds.strip_prefix("seeds:").is_some_and(|seeds| {
+        seeds
+            .split_whitespace()
+            .all(|seed| seed.parse::<usize>().is_ok())
+    }) {
+        return Err(AlmanacError::Unreadable(format!("seeds {:?}", seeds)));
+    }
+    if let Some(finding) = lint::lint_almanac(input).into_iter().find(|finding| {
+        matches!(
+            finding.issue,
+            lint::Issue::Malformed | lint::Issue::ZeroLength | lint::Issue::Overflow
+        )
+    }) {
+        return Err(AlmanacError::Unreadable(finding.to_string()));
+    }
+    parse_almanac(input).map(|almanac| almanac.to_string())
+}
+
+/// Finds the lowest location of any listed seed.
+pub fn part1(input: String) -> usize {
+    let almanac = parse_almanac(&input).unwrap_or_else(|error| panic!("{}", error));
+    let chain = almanac
+        .chain("seed", "location")
+        .unwrap_or_else(|error| panic!("{}", error));
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| chain.lookup(*seed))
+        .fold(usize::MAX, |acc, location| acc.min(location))
+}
+
+/// Finds the lowest location of any seed in the listed ranges by asking
+/// the composed almanac for its lowest destination, never the seeds one by
+/// one.
+pub fn part2(input: String) -> usize {
+    let almanac = parse_almanac(&input).unwrap_or_else(|error| panic!("{}", error));
+    almanac
+        .chain("seed", "location")
+        .unwrap_or_else(|error| panic!("{}", error))
+        .lowest(&almanac.seed_ranges())
+        .map(|(_, location)| location)
+        .unwrap_or(usize::MAX)
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_finds_values() {
+        let range = AocRange {
+            min: 98,
+            max: 99,
+            base: 50,
+        };
+        assert_eq!(Some(50), range.get_value(98));
+        assert_eq!(Some(51), range.get_value(99));
+        assert_eq!(None, range.get_value(97));
+    }
+
+    #[test]
+    fn map_finds_values() {
+        let map = AocMap(vec![
+            AocRange {
+                min: 98,
+                max: 99,
+                base: 50,
+            },
+            AocRange {
+                min: 50,
+                max: 97,
+                base: 52,
+            },
+        ]);
+        assert_eq!(50, map.get_value(98));
+        assert_eq!(51, map.get_value(99));
+        assert_eq!(52, map.get_value(50));
+        assert_eq!(53, map.get_value(51));
+        assert_eq!(97, map.get_value(95));
+        assert_eq!(100, map.get_value(100));
+    }
+
+    #[test]
+    fn map_splits_ranges_at_edges() {
+        let map = parse_to_map("50 98 2\n52 50 48");
+        assert_eq!(vec![52..62], map.map_range(50..60));
+        assert_eq!(vec![40..50, 52..54], map.map_range(40..52));
+        assert_eq!(vec![99..100, 50..52, 100..105], map.map_range(97..105));
+        assert_eq!(vec![0..10], map.map_range(0..10));
+        assert_eq!(Vec::<Range<usize>>::new(), map.map_range(5..5));
+    }
+
+    #[test]
+    fn map_ranges_agree_with_single_values() {
+        let map = parse_to_map("0 15 37\n37 52 2\n39 0 15");
+        for start in 0..60 {
+            for end in start..60 {
+                let mut mapped = map
+                    .map_range(start..end)
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<usize>>();
+                let mut expected = (start..end)
+                    .map(|value| map.get_value(value))
+                    .collect::<Vec<usize>>();
+                mapped.sort();
+                expected.sort();
+                assert_eq!(expected, mapped, "{}..{}", start, end);
+            }
+        }
+    }
+
+    const EXAMPLE_MAPS: &str = "seed-to-soil map:
+        50 98 2
+        52 50 48
+
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+
+        water-to-light map:
+        88 18 7
+        18 25 70
+
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+
+        humidity-to-location map:
+        60 56 37
+        56 93 4";
+
+    fn example_maps() -> Vec<AocMap> {
+        parse_maps(EXAMPLE_MAPS.split("\n\n"))
+    }
+
+    #[test]
+    fn composed_map_agrees_with_chain() {
+        let maps = example_maps();
+        let almanac = AocMap::compose(&maps);
+        let mut sorted = almanac.0.clone();
+        sorted.sort_by_key(|aoc_range| aoc_range.min);
+        assert_eq!(sorted, almanac.0);
+        assert!(almanac.0.windows(2).all(|pair| pair[0].max < pair[1].min));
+        for seed in 0..200 {
+            let expected = maps.iter().fold(seed, |value, map| map.get_value(value));
+            assert_eq!(expected, almanac.lookup(seed), "{}", seed);
+        }
+        assert_eq!(
+            AocMap(vec![
+                AocRange {
+                    min: 0,
+                    max: 9,
+                    base: 12,
+                },
+                AocRange {
+                    min: 10,
+                    max: 19,
+                    base: 12,
+                },
+            ]),
+            parse_to_map("10 0 10").then(&parse_to_map("12 10 10"))
+        );
+        assert_eq!(
+            AocMap(vec![AocRange {
+                min: 10,
+                max: 19,
+                base: 0,
+            }]),
+            parse_to_map("10 0 10").then(&parse_to_map("0 10 10\n10 0 10"))
+        );
+    }
+
+    #[test]
+    fn composed_map_keeps_file_order_on_overlaps() {
+        let maps = [
+            parse_to_map("200 5 1\n100 0 10"),
+            parse_to_map("50 100 3\n0 95 20\n300 200 1"),
+        ];
+        assert_eq!(200, maps[0].get_value(5));
+        assert_eq!(
+            vec![
+                AocRange {
+                    min: 0,
+                    max: 4,
+                    base: 100,
+                },
+                AocRange {
+                    min: 5,
+                    max: 5,
+                    base: 200,
+                },
+                AocRange {
+                    min: 6,
+                    max: 9,
+                    base: 106,
+                },
+            ],
+            maps[0].disjoint()
+        );
+        let almanac = AocMap::compose(&maps);
+        for seed in 0..250 {
+            let expected = maps.iter().fold(seed, |value, map| map.get_value(value));
+            assert_eq!(expected, almanac.lookup(seed), "{}", seed);
+        }
+        let input = "seeds: 5 1\n\nseed-to-location map:\n200 5 1\n100 0 10";
+        assert_eq!(101, part1(input.to_string()));
+        assert_eq!(200, part2(input.to_string()));
+    }
+
+    #[test]
+    fn inverse_finds_seeds() {
+        let almanac = AocMap::compose(&example_maps());
+        assert_eq!(vec![82], almanac.preimage(46));
+        assert_eq!(vec![13], almanac.preimage(35));
+        for seed in 0..200 {
+            assert!(almanac.preimage(almanac.lookup(seed)).contains(&seed));
+        }
+        assert_eq!(
+            Some((82, 46)),
+            almanac.lowest(&parse_seeds_into_ranges("seeds: 79 14 55 13"))
+        );
+        assert_eq!(Some((13, 35)), almanac.lowest(&[13..14, 79..80]));
+        assert_eq!(None, almanac.lowest(&[]));
+    }
+
+    #[test]
+    fn parses_almanac_graph() {
+        let almanac = parse_almanac(&format!("seeds: 79 14 55 13\n\n{}", EXAMPLE_MAPS)).unwrap();
+        assert_eq!(8, almanac.categories().len());
+        assert_eq!(vec![79..93, 55..68], almanac.seed_ranges());
+        assert_eq!(
+            vec!["seed", "soil", "fertilizer", "water"],
+            almanac.path("seed", "water").unwrap()
+        );
+        assert_eq!(Ok(81), almanac.convert(79, "seed", "water"));
+        assert_eq!(Ok(82), almanac.convert(79, "seed", "location"));
+        assert_eq!(Ok(74), almanac.convert(81, "water", "light"));
+        assert_eq!(Ok(5), almanac.convert(5, "soil", "soil"));
+        assert_eq!(
+            Err(AlmanacError::NoPath {
+                from: "water".to_string(),
+                to: "seed".to_string(),
+            }),
+            almanac.convert(81, "water", "seed")
+        );
+    }
+
+    #[test]
+    fn follows_any_categories_in_any_order() {
+        let almanac = parse_almanac(
+            "seeds: 1
+
+            b-to-c map:
+            20 10 5
+
+            a-to-b map:
+            10 0 5
+
+            a-to-d map:
+            100 0 5",
+        )
+        .unwrap();
+        assert_eq!(Ok(21), almanac.convert(1, "a", "c"));
+        assert_eq!(Ok(101), almanac.convert(1, "a", "d"));
+    }
+
+    #[test]
+    fn rejects_bad_graphs() {
+        assert_eq!(
+            Err(AlmanacError::MissingHeader("50 98 2".to_string())),
+            parse_almanac("seeds: 1\n\n50 98 2")
+        );
+        assert_eq!(
+            Err(AlmanacError::DuplicateMap {
+                from: "a".to_string(),
+                to: "b".to_string(),
+            }),
+            parse_almanac("seeds: 1\n\na-to-b map:\n1 0 1\n\na-to-b map:\n1 0 1")
+        );
+        assert_eq!(
+            Err(AlmanacError::Cycle("a".to_string())),
+            parse_almanac("seeds: 1\n\na-to-b map:\n1 0 1\n\nb-to-a map:\n1 0 1")
+        );
+        let disconnected = parse_almanac("seeds: 1\n\na-to-b map:\n1 0 1\n\nc-to-d map:\n1 0 1");
+        assert_eq!(
+            Err(AlmanacError::Disconnected(vec![
+                "c".to_string(),
+                "d".to_string()
+            ])),
+            disconnected
+        );
+        assert_eq!(
+            "Unable to reach c, d from the other categories",
+            disconnected.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn part2_handles_huge_ranges() {
+        assert_eq!(
+            1,
+            part2("seeds: 0 4000000000\n\nseed-to-location map:\n5 0 1".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_to_map() {
+        let map = AocMap(vec![
+            AocRange {
+                min: 98,
+                max: 99,
+                base: 50,
+            },
+            AocRange {
+                min: 50,
+                max: 97,
+                base: 52,
+            },
+        ]);
+        assert_eq!(
+            map,
+            parse_to_map(
+                "50 98 2
+        52 50 48
+        "
+            )
+        );
+    }
+
+    #[test]
+    fn skips_zero_length_ranges() {
+        assert_eq!(
+            AocMap(vec![AocRange {
+                min: 0,
+                max: 0,
+                base: 5,
+            }]),
+            parse_to_map("7 0 0\n5 0 1")
+        );
+    }
+
+    #[test]
+    fn skips_overflowing_ranges() {
+        let input = format!("0 {} 2\n{} 0 2\n5 0 1", usize::MAX, usize::MAX);
+        assert_eq!(
+            AocMap(vec![AocRange {
+                min: 0,
+                max: 0,
+                base: 5,
+            }]),
+            parse_to_map(&input)
+        );
+        assert_eq!(
+            AocMap(Vec::new()),
+            parse_to_map(&format!("0 {} 1", usize::MAX))
+        );
+        assert_eq!(
+            AocMap(Vec::new()),
+            parse_to_map(&format!("{} 0 1", usize::MAX))
+        );
+        assert_eq!(
+            AocMap(vec![AocRange {
+                min: usize::MAX - 1,
+                max: usize::MAX - 1,
+                base: usize::MAX - 1,
+            }]),
+            parse_to_map(&format!("{} {} 1", usize::MAX - 1, usize::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn solves_with_ranges_at_the_top_of_usize() {
+        let input = format!("seeds: 1 1\n\nseed-to-location map:\n{} 0 1", usize::MAX);
+        assert_eq!(1, part1(input.clone()));
+        assert_eq!(1, part2(input));
+        let input = format!(
+            "seeds: 0 3\n\nseed-to-location map:\n{} 0 2\n10 2 1",
+            usize::MAX - 2
+        );
+        assert_eq!(3, part1(input.clone()));
+        assert_eq!(10, part2(input));
+        let almanac = AocMap::compose(&[parse_to_map(&format!("{} 0 2", usize::MAX - 2))]);
+        assert_eq!(usize::MAX - 1, almanac.lookup(1));
+        assert_eq!(vec![usize::MAX - 1..usize::MAX], almanac.map_range(1..2));
+    }
+
+    #[test]
+    fn map_can_print() {
+        let input = "50 98 2\n52 50 48\n";
+        let map = parse_to_map(input);
+        assert_eq!(input, map.to_string());
+        assert_eq!(map, parse_to_map(&map.to_string()));
+    }
+
+    #[test]
+    fn parses_seeds() {
+        assert_eq!(vec![79, 14, 55, 13], parse_seeds("seeds: 79 14 55 13"));
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            35,
+            part1(
+                "seeds: 79 14 55 13
+
+                seed-to-soil map:
+                50 98 2
+                52 50 48
+
+                soil-to-fertilizer map:
+                0 15 37
+                37 52 2
+                39 0 15
+
+                fertilizer-to-water map:
+                49 53 8
+                0 11 42
+                42 0 7
+                57 7 4
+
+                water-to-light map:
+                88 18 7
+                18 25 70
+
+                light-to-temperature map:
+                45 77 23
+                81 45 19
+                68 64 13
+
+                temperature-to-humidity map:
+                0 69 1
+                1 0 69
+
+                humidity-to-location map:
+                60 56 37
+                56 93 4
+                "
+                .to_string()
+            )
+        )
+    }
+
+    #[test]
+    fn parses_seeds_into_ranges() {
+        assert_eq!(
+            vec![79..93, 55..68],
+            parse_seeds_into_ranges("seeds: 79 14 55 13")
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            46,
+            part2(
+                "seeds: 79 14 55 13
+
+                seed-to-soil map:
+                50 98 2
+                52 50 48
+
+                soil-to-fertilizer map:
+                0 15 37
+                37 52 2
+                39 0 15
+
+                fertilizer-to-water map:
+                49 53 8
+                0 11 42
+                42 0 7
+                57 7 4
+
+                water-to-light map:
+                88 18 7
+                18 25 70
+
+                light-to-temperature map:
+                45 77 23
+                81 45 19
+                68 64 13
+
+                temperature-to-humidity map:
+                0 69 1
+                1 0 69
+
+                humidity-to-location map:
+                60 56 37
+                56 93 4
+                "
+                .to_string()
+            )
+        )
+    }
+
+    #[test]
+    fn formats_input() {
+        assert_eq!(
+            Ok(
+                "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\nsoil-to-fertilizer map:\n0 15 37\n"
+                    .to_string()
+            ),
+            format_input(
+                "seeds:  79 14
+
+                soil-to-fertilizer map:
+                0 15 37
+
+                seed-to-soil map:
+                50  98 2"
+            )
+        );
+        assert!(format_input("seeds: 1\n\nbroken\n1 2 3").is_err());
+    }
+
+    #[test]
+    fn formatting_round_trips() {
+        let input = "seeds: 79  14 55 13\n\n  soil-to-fertilizer map:\n0 15 37\n37 52 2\n\nseed-to-soil map:\n50 98 2\n52 50 48\n";
+        let formatted = format_input(input).unwrap();
+        assert_eq!(parse_almanac(input), parse_almanac(&formatted));
+        assert_eq!(Ok(formatted.clone()), format_input(&formatted));
+        assert_eq!(
+            Err(AlmanacError::Unreadable(
+                "line 4 (seed-to-soil): range has length 0".to_string()
+            )),
+            format_input("seeds: 1\n\nseed-to-soil map:\n7 0 0\n5 0 1\n")
+        );
+        assert!(format_input("seeds: 1\n\nseed-to-soil map:\n5 0\n").is_err());
+        assert!(format_input("seeds: 1 x\n\nseed-to-soil map:\n5 0 1\n").is_err());
+        assert!(format_input(&format!(
+            "seeds: 1\n\nseed-to-soil map:\n0 {} 2\n",
+            usize::MAX
+        ))
+        .is_err());
+    }
+}
@@ -0,0 +1,249 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks an almanac's maps for ranges the solver would read ambiguously
+//! or not at all.
+
+/// Something questionable about a map line or a whole map.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Issue {
+    /// The line isn't three numbers
+    Malformed,
+    /// The range has length 0, so it maps nothing
+    ZeroLength,
+    /// The range's source or destination exclusive end doesn't fit in a
+    /// usize
+    Overflow,
+    /// The source range overlaps the one on an earlier line, so only the
+    /// earlier one is used for the shared values
+    Overlap { other: usize },
+    /// Values between two ranges that map to themselves
+    Gap { min: usize, max: usize },
+}
+
+/// An issue, the map it's in and the 1-based line it's on. Gaps belong to
+/// the map as a whole and point at its header.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Finding {
+    pub line: usize,
+    pub map: String,
+    pub issue: Issue,
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "line {} ({}): ", self.line, self.map)?;
+        match &self.issue {
+            Issue::Malformed => write!(f, "expected `destination source length`"),
+            Issue::ZeroLength => write!(f, "range has length 0"),
+            Issue::Overflow => write!(f, "range end overflows"),
+            Issue::Overlap { other } => {
+                write!(f, "source range overlaps the one on line {}", other)
+            }
+            Issue::Gap { min, max } => write!(f, "{}..={} maps to itself", min, max),
+        }
+    }
+}
+
+struct Line {
+    number: usize,
+    min: usize,
+    max: usize,
+}
+
+fn lint_map(header: usize, map: &str, lines: &[(usize, &str)]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut finding = |line: usize, issue: Issue| {
+        findings.push(Finding {
+            line,
+            map: map.to_string(),
+            issue,
+        })
+    };
+    let mut ranges = Vec::new();
+    for (number, line) in lines {
+        let numbers = line
+            .split_whitespace()
+            .map(|part| part.parse::<usize>())
+            .collect::<Result<Vec<usize>, _>>();
+        let (destination, source, length) = match numbers.as_deref() {
+            Ok([destination, source, length]) => (*destination, *source, *length),
+            _ => {
+                finding(*number, Issue::Malformed);
+                continue;
+            }
+        };
+        if 0 == length {
+            finding(*number, Issue::ZeroLength);
+            continue;
+        }
+        match (source.checked_add(length), destination.checked_add(length)) {
+            (Some(end), Some(_)) => ranges.push(Line {
+                number: *number,
+                min: source,
+                max: end - 1,
+            }),
+            _ => finding(*number, Issue::Overflow),
+        }
+    }
+    for (index, range) in ranges.iter().enumerate() {
+        for earlier in &ranges[..index] {
+            if range.min <= earlier.max && earlier.min <= range.max {
+                finding(
+                    range.number,
+                    Issue::Overlap {
+                        other: earlier.number,
+                    },
+                );
+                break;
+            }
+        }
+    }
+    ranges.sort_by_key(|range| range.min);
+    let mut covered: Option<usize> = None;
+    for range in ranges {
+        if let Some(next) = covered.and_then(|end| end.checked_add(1)) {
+            if range.min > next {
+                finding(
+                    header,
+                    Issue::Gap {
+                        min: next,
+                        max: range.min - 1,
+                    },
+                );
+            }
+        }
+        covered = Some(covered.map_or(range.max, |end| end.max(range.max)));
+    }
+    findings
+}
+
+/// Lints every `X map:` section in an almanac, in file order.
+pub fn lint_almanac(input: &str) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut section: Option<(usize, &str)> = None;
+    let mut lines = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if let Some(map) = line.strip_suffix(" map:") {
+            if let Some((header, map)) = section {
+                findings.extend(lint_map(header, map, &lines));
+            }
+            section = Some((index + 1, map));
+            lines.clear();
+        } else if section.is_some() && !line.is_empty() {
+            lines.push((index + 1, line));
+        }
+    }
+    if let Some((header, map)) = section {
+        findings.extend(lint_map(header, map, &lines));
+    }
+    findings
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_clean_maps() {
+        assert_eq!(
+            Vec::<Finding>::new(),
+            lint_almanac("seeds: 1\n\nseed-to-soil map:\n50 98 2\n52 50 48\n")
+        );
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        let findings = lint_almanac(
+            "seeds: 1
+
+            seed-to-soil map:
+            50 98 2
+            52 50 0
+            1 2
+            0 18446744073709551615 2
+            52 90 10
+
+            soil-to-fertilizer map:
+            0 0 5
+            0 10 5",
+        );
+        assert_eq!(
+            vec![
+                Finding {
+                    line: 5,
+                    map: "seed-to-soil".to_string(),
+                    issue: Issue::ZeroLength,
+                },
+                Finding {
+                    line: 6,
+                    map: "seed-to-soil".to_string(),
+                    issue: Issue::Malformed,
+                },
+                Finding {
+                    line: 7,
+                    map: "seed-to-soil".to_string(),
+                    issue: Issue::Overflow,
+                },
+                Finding {
+                    line: 8,
+                    map: "seed-to-soil".to_string(),
+                    issue: Issue::Overlap { other: 4 },
+                },
+                Finding {
+                    line: 10,
+                    map: "soil-to-fertilizer".to_string(),
+                    issue: Issue::Gap { min: 5, max: 9 },
+                },
+            ],
+            findings
+        );
+        assert_eq!(
+            vec![
+                "line 5 (seed-to-soil): range has length 0",
+                "line 6 (seed-to-soil): expected `destination source length`",
+                "line 7 (seed-to-soil): range end overflows",
+                "line 8 (seed-to-soil): source range overlaps the one on line 4",
+                "line 10 (soil-to-fertilizer): 5..=9 maps to itself",
+            ],
+            findings
+                .iter()
+                .map(|finding| finding.to_string())
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn reports_ranges_reaching_the_top_of_usize() {
+        let findings = lint_almanac(&format!(
+            "seeds: 1\n\nseed-to-soil map:\n0 1 {}\n0 5 1\n{} 0 1\n0 {} 1",
+            usize::MAX,
+            usize::MAX,
+            usize::MAX
+        ));
+        assert_eq!(
+            vec![
+                (4, Issue::Overflow),
+                (6, Issue::Overflow),
+                (7, Issue::Overflow)
+            ],
+            findings
+                .into_iter()
+                .map(|finding| (finding.line, finding.issue))
+                .collect::<Vec<(usize, Issue)>>()
+        );
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advent of Code 2023 day 6: Wait For It
+
+use num_bigint::BigUint;
+use num_integer::{Integer, Roots};
+
+pub mod models;
+
+use models::{count_holds, BoatModel};
+
+/// Whether holding the button for `hold` beats the record.
+fn beats<T: Integer + Clone>(hold: &T, time: &T, distance: &T) -> bool {
+    hold.clone() * (time.clone() - hold.clone()) > *distance
+}
+
+/// The shortest and longest hold times that beat the record, if any do.
+/// Holding for `h` travels `h * (time - h)`, so the winners lie strictly
+/// between the roots of `h^2 - time * h + distance`. The roots come from an
+/// exact integer square root and are then nudged onto the right integers,
+/// so this works for any size of number without floating point.
+pub fn winning_range<T: Integer + Roots + Clone + From<u8>>(
+    time: T,
+    distance: T,
+) -> Option<(T, T)> {
+    let two = T::from(2);
+    let four = T::from(4);
+    let square = time.clone() * time.clone();
+    let product = four * distance.clone();
+    if square <= product {
+        return None;
+    }
+    let root = (square - product).sqrt();
+    let mut shortest = (time.clone() - root) / two.clone();
+    while shortest > T::zero() && beats(&(shortest.clone() - T::one()), &time, &distance) {
+        shortest = shortest - T::one();
+    }
+    while shortest.clone() * two.clone() <= time && !beats(&shortest, &time, &distance) {
+        shortest = shortest + T::one();
+    }
+    if shortest.clone() * two > time {
+        return None;
+    }
+    let longest = time - shortest.clone();
+    Some((shortest, longest))
+}
+
+/// Counts the hold times that beat the record, 0 if none do.
+pub fn count_wins<T: Integer + Roots + Clone + From<u8>>(time: T, distance: T) -> T {
+    match winning_range(time, distance) {
+        Some((shortest, longest)) => longest - shortest + T::one(),
+        None => T::zero(),
+    }
+}
+
+/// Counts the hold times that beat the record distance.
+pub fn find_race_wins(time: usize, distance: usize) -> usize {
+    count_wins(time as u128, distance as u128) as usize
+}
+
+/// Parses the `Time:` and `Distance:` lines into races.
+pub fn parse_input(input: String) -> Vec<(usize, usize)> {
+    let input = input.trim();
+    let mut lines = input.lines();
+    let mut times = lines.next().unwrap().split_whitespace();
+    let mut distances = lines.next().unwrap().split_whitespace();
+    times.next();
+    distances.next();
+    times
+        .zip(distances)
+        .map(|(time, distance)| (time.parse().unwrap(), distance.parse().unwrap()))
+        .collect()
+}
+
+/// Multiplies the number of ways to win each race.
+pub fn part1(input: String) -> usize {
+    parse_input(input)
+        .iter()
+        .map(|(time, distance)| find_race_wins(*time, *distance))
+        .fold(1, |acc, x| acc * x)
+}
+
+/// The single race's time and distance digits, ignoring the spaces.
+fn merged_race(input: &str) -> (String, String) {
+    let mut lines = input.trim().lines();
+    let mut time = lines.next().unwrap().to_string();
+    time.retain(|c| c.is_ascii_digit());
+    let mut distance = lines.next().unwrap().to_string();
+    distance.retain(|c| c.is_ascii_digit());
+    (time, distance)
+}
+
+/// Counts the ways to win the single race formed by ignoring spaces. The
+/// merged numbers can be any length.
+pub fn part2(input: String) -> BigUint {
+    let (time, distance) = merged_race(&input);
+    let time = time.parse::<BigUint>().expect("Unable to parse time");
+    let distance = distance
+        .parse::<BigUint>()
+        .expect("Unable to parse distance");
+    count_wins(time, distance)
+}
+
+/// Multiplies the number of ways to win each race under `model`.
+pub fn part1_with_model(input: String, model: &dyn BoatModel) -> u128 {
+    parse_input(input)
+        .iter()
+        .map(|(time, distance)| count_holds(model, *time as u128, *distance as u128))
+        .product()
+}
+
+/// Counts the ways to win the merged race under `model`. Unlike [`part2`],
+/// the models only race in u128s, so longer races are an error.
+pub fn part2_with_model(input: String, model: &dyn BoatModel) -> Result<u128, String> {
+    let (time, distance) = merged_race(&input);
+    let time = time
+        .parse::<u128>()
+        .map_err(|_| format!("Race time {} does not fit in a u128", time))?;
+    let distance = distance
+        .parse::<u128>()
+        .map_err(|_| format!("Race distance {} does not fit in a u128", distance))?;
+    Ok(count_holds(model, time, distance))
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_proper_race_wins() {
+        assert_eq!(4, find_race_wins(7, 9));
+        assert_eq!(8, find_race_wins(15, 40));
+        assert_eq!(9, find_race_wins(30, 200));
+    }
+
+    #[test]
+    fn handles_races_with_few_wins() {
+        assert_eq!(None, winning_range(0_u128, 0));
+        assert_eq!(None, winning_range(2_u128, 1));
+        assert_eq!(None, winning_range(4_u128, 4));
+        assert_eq!(Some((1, 1)), winning_range(2_u128, 0));
+        assert_eq!(Some((2, 2)), winning_range(4_u128, 3));
+        assert_eq!(Some((2, 3)), winning_range(5_u128, 5));
+        assert_eq!(0, find_race_wins(10, 1000));
+        assert_eq!(1, find_race_wins(4, 3));
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        for time in 0..60_u128 {
+            for distance in 0..(time * time / 4 + 3) {
+                let expected = (0..=time)
+                    .filter(|hold| hold * (time - hold) > distance)
+                    .count() as u128;
+                assert_eq!(
+                    expected,
+                    count_wins(time, distance),
+                    "{} {}",
+                    time,
+                    distance
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn handles_huge_races() {
+        assert_eq!(Some((2, 71528)), winning_range(71530_u128, 71529));
+        let time = "1".repeat(60).parse::<BigUint>().unwrap();
+        let distance = BigUint::from(0_u8);
+        assert_eq!(
+            time.clone() - BigUint::from(1_u8),
+            count_wins(time, distance)
+        );
+        assert_eq!(
+            BigUint::from(1_u8),
+            count_wins(
+                BigUint::from(2_u8) * "1".repeat(40).parse::<BigUint>().unwrap(),
+                "1".repeat(40).parse::<BigUint>().unwrap().pow(2) - BigUint::from(1_u8)
+            )
+        );
+    }
+
+    #[test]
+    fn can_parse_input() {
+        assert_eq!(
+            vec![(7, 9), (15, 40), (30, 200),],
+            parse_input(
+                "Time:      7  15   30
+                Distance:  9  40  200
+                "
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            288,
+            part1(
+                "Time:      7  15   30
+                Distance:  9  40  200
+                "
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            BigUint::from(71503_u32),
+            part2(
+                "Time:      7  15   30
+                Distance:  9  40  200
+                "
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn solves_both_parts_under_models() {
+        let input = "Time:      7  15   30
+            Distance:  9  40  200
+            ";
+        assert_eq!(288, part1_with_model(input.to_string(), &models::Linear));
+        assert_eq!(
+            Ok(71503),
+            part2_with_model(input.to_string(), &models::Linear)
+        );
+        assert_eq!(
+            288,
+            part1_with_model(input.to_string(), &models::Capped { cap: 30 })
+        );
+        assert_eq!(
+            288,
+            part1_with_model(input.to_string(), &models::Accelerating { power: 1 })
+        );
+        assert_eq!(
+            0,
+            part1_with_model(input.to_string(), &models::ChargeCost { cost: 1 })
+        );
+        assert_eq!(
+            Ok(71503),
+            part2_with_model(input.to_string(), &models::Legs { legs: 1 })
+        );
+        let long = format!("Time: {}\nDistance: 9\n", "9".repeat(40));
+        assert_eq!(
+            Err(format!(
+                "Race time {} does not fit in a u128",
+                "9".repeat(40)
+            )),
+            part2_with_model(long, &models::Linear)
+        );
+    }
+}
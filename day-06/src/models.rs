@@ -0,0 +1,430 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Boats that turn hold time into distance in ways other than the puzzle's,
+//! and the solvers that find which hold times win under them.
+
+use std::ops::RangeInclusive;
+
+use num_bigint::BigInt;
+
+/// How far a boat goes for a given hold time.
+pub trait BoatModel {
+    /// A short name to report the model under.
+    fn name(&self) -> String;
+
+    /// The distance travelled holding for `hold` in a race lasting `time`.
+    fn distance(&self, hold: u128, time: u128) -> u128;
+
+    /// The distance as a polynomial in the hold time, lowest power first,
+    /// if it is one whose coefficients fit in an i128. Polynomial models are
+    /// solved exactly; the rest must rise strictly to a peak and then fall,
+    /// and are solved by bisection.
+    fn polynomial(&self, _time: u128) -> Option<Vec<i128>> {
+        None
+    }
+}
+
+/// Speed equals hold time. The puzzle's model.
+pub struct Linear;
+
+impl BoatModel for Linear {
+    fn name(&self) -> String {
+        "linear".to_string()
+    }
+
+    fn distance(&self, hold: u128, time: u128) -> u128 {
+        hold.saturating_mul(time.saturating_sub(hold))
+    }
+
+    fn polynomial(&self, time: u128) -> Option<Vec<i128>> {
+        Some(vec![0, i128::try_from(time).ok()?, -1])
+    }
+}
+
+/// The highest power [`Accelerating`] is solved exactly at. Every power adds
+/// a level of root bracketing, so higher ones are bisected instead.
+pub const MAX_EXACT_POWER: u32 = 16;
+
+/// Speed grows as a power of hold time, so 2 charges quadratically.
+pub struct Accelerating {
+    pub power: u32,
+}
+
+impl BoatModel for Accelerating {
+    fn name(&self) -> String {
+        format!("accelerating={}", self.power)
+    }
+
+    fn distance(&self, hold: u128, time: u128) -> u128 {
+        hold.saturating_pow(self.power)
+            .saturating_mul(time.saturating_sub(hold))
+    }
+
+    fn polynomial(&self, time: u128) -> Option<Vec<i128>> {
+        if self.power > MAX_EXACT_POWER {
+            return None;
+        }
+        let mut coefficients = vec![0; self.power as usize + 2];
+        coefficients[self.power as usize] = i128::try_from(time).ok()?;
+        coefficients[self.power as usize + 1] = -1;
+        Some(coefficients)
+    }
+}
+
+/// Every millisecond held costs `cost` millimetres of the distance.
+pub struct ChargeCost {
+    pub cost: u128,
+}
+
+impl BoatModel for ChargeCost {
+    fn name(&self) -> String {
+        format!("charge-cost={}", self.cost)
+    }
+
+    fn distance(&self, hold: u128, time: u128) -> u128 {
+        Linear
+            .distance(hold, time)
+            .saturating_sub(hold.saturating_mul(self.cost))
+    }
+
+    fn polynomial(&self, time: u128) -> Option<Vec<i128>> {
+        let time = i128::try_from(time).ok()?;
+        Some(vec![0, time - i128::try_from(self.cost).ok()?, -1])
+    }
+}
+
+/// Speed stops growing once the hold reaches `cap`.
+pub struct Capped {
+    pub cap: u128,
+}
+
+impl BoatModel for Capped {
+    fn name(&self) -> String {
+        format!("capped={}", self.cap)
+    }
+
+    fn distance(&self, hold: u128, time: u128) -> u128 {
+        hold.min(self.cap).saturating_mul(time.saturating_sub(hold))
+    }
+}
+
+/// The race is split into `legs` equal legs, any remainder unused, and the
+/// same hold is spent at the start of each.
+pub struct Legs {
+    pub legs: u128,
+}
+
+impl BoatModel for Legs {
+    fn name(&self) -> String {
+        format!("legs={}", self.legs)
+    }
+
+    fn distance(&self, hold: u128, time: u128) -> u128 {
+        match time.checked_div(self.legs) {
+            Some(leg) => Linear.distance(hold, leg).saturating_mul(self.legs),
+            None => 0,
+        }
+    }
+
+    fn polynomial(&self, time: u128) -> Option<Vec<i128>> {
+        let leg = time.checked_div(self.legs).unwrap_or(0);
+        let legs = i128::try_from(self.legs).ok()?;
+        Some(vec![0, legs.checked_mul(i128::try_from(leg).ok()?)?, -legs])
+    }
+}
+
+/// Parses a model like `linear`, `accelerating=2`, `charge-cost=3`,
+/// `capped=10` or `legs=2`.
+pub fn parse_model(spec: &str) -> Result<Box<dyn BoatModel>, String> {
+    let (name, value) = spec.trim().split_once('=').unwrap_or((spec.trim(), ""));
+    let number = || {
+        value
+            .trim()
+            .parse::<u128>()
+            .map_err(|_| format!("Unable to parse {:?} for model {}", value, name))
+    };
+    match name.trim() {
+        "linear" => Ok(Box::new(Linear)),
+        "accelerating" => Ok(Box::new(Accelerating {
+            power: u32::try_from(number()?).map_err(|_| "Power too large".to_string())?,
+        })),
+        "charge-cost" => Ok(Box::new(ChargeCost { cost: number()? })),
+        "capped" => Ok(Box::new(Capped { cap: number()? })),
+        "legs" => Ok(Box::new(Legs { legs: number()? })),
+        _ => Err(format!("Unknown model {:?}", name)),
+    }
+}
+
+/// The first value in `min..=max` where `predicate` holds, given it is
+/// false up to some point and true from then on.
+fn first_true(min: u128, max: u128, predicate: impl Fn(u128) -> bool) -> Option<u128> {
+    if min > max || !predicate(max) {
+        return None;
+    }
+    let (mut low, mut high) = (min, max);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if predicate(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    Some(low)
+}
+
+fn evaluate(coefficients: &[BigInt], x: u128) -> BigInt {
+    let x = BigInt::from(x);
+    coefficients
+        .iter()
+        .rev()
+        .fold(BigInt::ZERO, |total, coefficient| total * &x + coefficient)
+}
+
+fn derivative(coefficients: &[BigInt]) -> Vec<BigInt> {
+    coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(power, coefficient)| coefficient * BigInt::from(power))
+        .collect()
+}
+
+/// Splits `min..=max` into pieces the polynomial is monotone over. Each
+/// piece is either free of turning points or at most two integers wide,
+/// found by bracketing the derivative's sign changes the same way.
+fn monotone_pieces(coefficients: &[BigInt], min: u128, max: u128) -> Vec<(u128, u128)> {
+    if coefficients.len() <= 2 {
+        return vec![(min, max)];
+    }
+    let slope = derivative(coefficients);
+    let mut breaks = vec![min];
+    for (start, end) in monotone_pieces(&slope, min, max) {
+        breaks.push(start);
+        let rising = evaluate(&slope, start) <= evaluate(&slope, end);
+        let flipped = |x: u128| {
+            let value = evaluate(&slope, x);
+            if rising {
+                value > BigInt::ZERO
+            } else {
+                value < BigInt::ZERO
+            }
+        };
+        if let Some(flip) = first_true(start, end, flipped).filter(|flip| *flip > start) {
+            breaks.extend([flip - 1, flip]);
+        }
+        breaks.push(end);
+    }
+    breaks.push(max);
+    breaks.sort_unstable();
+    breaks.dedup();
+    if 1 == breaks.len() {
+        return vec![(min, max)];
+    }
+    breaks.windows(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+/// Adds a range of winners, joining it to the last one if they touch.
+fn push_range(ranges: &mut Vec<RangeInclusive<u128>>, range: RangeInclusive<u128>) {
+    if let Some(last) = ranges.last_mut() {
+        if *range.start() <= last.end().saturating_add(1) {
+            *last = *last.start()..=*last.end().max(range.end());
+            return;
+        }
+    }
+    ranges.push(range);
+}
+
+/// The hold times beating `record` under a polynomial distance, found
+/// exactly by splitting the race where the polynomial turns.
+pub fn solve_polynomial(
+    coefficients: &[i128],
+    time: u128,
+    record: u128,
+) -> Vec<RangeInclusive<u128>> {
+    let coefficients = coefficients
+        .iter()
+        .map(|coefficient| BigInt::from(*coefficient))
+        .collect::<Vec<BigInt>>();
+    let record = BigInt::from(record);
+    let wins = |x: u128| evaluate(&coefficients, x) > record;
+    let mut ranges = Vec::new();
+    for (start, end) in monotone_pieces(&coefficients, 0, time) {
+        let range = if evaluate(&coefficients, start) <= evaluate(&coefficients, end) {
+            first_true(start, end, wins).map(|first| first..=end)
+        } else {
+            first_true(start, end, |x| !wins(x))
+                .map_or(Some(end), |first| first.checked_sub(1))
+                .filter(|last| *last >= start)
+                .map(|last| start..=last)
+        };
+        if let Some(range) = range {
+            push_range(&mut ranges, range);
+        }
+    }
+    ranges
+}
+
+/// The hold times beating `record` under a model that rises strictly to
+/// its peak and then falls, found by bisecting each side of the peak.
+pub fn solve_unimodal(
+    model: &dyn BoatModel,
+    time: u128,
+    record: u128,
+) -> Vec<RangeInclusive<u128>> {
+    let distance = |hold: u128| model.distance(hold, time);
+    let peak = first_true(0, time.saturating_sub(1), |hold| {
+        distance(hold) >= distance(hold + 1)
+    })
+    .unwrap_or(time);
+    if distance(peak) <= record {
+        return Vec::new();
+    }
+    let shortest = first_true(0, peak, |hold| distance(hold) > record).unwrap_or(peak);
+    let longest =
+        first_true(peak, time, |hold| distance(hold) <= record).map_or(time, |first| first - 1);
+    vec![shortest..=longest]
+}
+
+/// The hold times beating `record`, using the exact solver when the model
+/// gives a polynomial for this race and bisection otherwise.
+pub fn winning_holds(model: &dyn BoatModel, time: u128, record: u128) -> Vec<RangeInclusive<u128>> {
+    match model.polynomial(time) {
+        Some(coefficients) => solve_polynomial(&coefficients, time, record),
+        None => solve_unimodal(model, time, record),
+    }
+}
+
+/// Counts the hold times beating `record`.
+pub fn count_holds(model: &dyn BoatModel, time: u128, record: u128) -> u128 {
+    winning_holds(model, time, record)
+        .iter()
+        .map(|range| range.end() - range.start() + 1)
+        .sum()
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(model: &dyn BoatModel, time: u128, record: u128) -> u128 {
+        (0..=time)
+            .filter(|hold| model.distance(*hold, time) > record)
+            .count() as u128
+    }
+
+    #[test]
+    fn solves_polynomial_models() {
+        assert_eq!(vec![2..=5], winning_holds(&Linear, 7, 9));
+        assert_eq!(
+            vec![14..=25],
+            winning_holds(&Accelerating { power: 2 }, 30, 3000)
+        );
+        assert_eq!(vec![3..=3], solve_polynomial(&[0, 0, 0, 0, 1], 3, 80));
+        assert_eq!(
+            vec![0..=0, 3..=4, 7..=7],
+            solve_polynomial(&[60, -112, 65, -14, 1], 7, 10)
+        );
+        assert_eq!(
+            Vec::<RangeInclusive<u128>>::new(),
+            winning_holds(&Linear, 4, 4)
+        );
+        for time in 0..40 {
+            for record in 0..(time * time / 2 + 2) {
+                for model in [
+                    &Linear as &dyn BoatModel,
+                    &Accelerating { power: 3 },
+                    &ChargeCost { cost: 4 },
+                    &Legs { legs: 3 },
+                ] {
+                    assert_eq!(
+                        brute_force(model, time, record),
+                        count_holds(model, time, record),
+                        "{} {} {}",
+                        model.name(),
+                        time,
+                        record
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solves_unimodal_models() {
+        assert_eq!(vec![2..=5], solve_unimodal(&Linear, 7, 9));
+        assert_eq!(vec![4..=9], winning_holds(&Capped { cap: 5 }, 30, 100));
+        for time in 0..40 {
+            for record in 0..(time * time / 2 + 2) {
+                for cap in [0, 1, 4, 100] {
+                    let model = Capped { cap };
+                    assert_eq!(
+                        brute_force(&model, time, record),
+                        count_holds(&model, time, record),
+                        "{} {} {}",
+                        cap,
+                        time,
+                        record
+                    );
+                }
+                assert_eq!(
+                    count_holds(&Linear, time, record),
+                    solve_unimodal(&Linear, time, record)
+                        .iter()
+                        .map(|range| range.end() - range.start() + 1)
+                        .sum::<u128>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parses_models() {
+        for spec in [
+            "linear",
+            "accelerating=2",
+            "charge-cost=3",
+            "capped=10",
+            "legs=2",
+        ] {
+            assert_eq!(spec, parse_model(spec).unwrap().name());
+        }
+        assert!(parse_model("capped").is_err());
+        assert!(parse_model("legs=two").is_err());
+        assert!(parse_model("rocket=1").is_err());
+    }
+
+    #[test]
+    fn falls_back_to_bisection() {
+        let huge = u128::MAX - 1;
+        assert_eq!(None, Linear.polynomial(huge));
+        assert_eq!(None, ChargeCost { cost: 1 }.polynomial(huge));
+        assert_eq!(None, Legs { legs: huge }.polynomial(huge));
+        assert_eq!(None, Accelerating { power: 2 }.polynomial(huge));
+        assert_eq!(
+            solve_unimodal(&Linear, huge, huge),
+            winning_holds(&Linear, huge, huge)
+        );
+        assert_eq!(vec![1..=huge - 1], winning_holds(&Linear, huge, 0));
+        let steep = Accelerating { power: 20000 };
+        assert_eq!(None, steep.polynomial(30));
+        assert_eq!(brute_force(&steep, 30, 1000), count_holds(&steep, 30, 1000));
+        assert_eq!(
+            brute_force(&Accelerating { power: 40 }, 50, 10),
+            count_holds(&Accelerating { power: 40 }, 50, 10)
+        );
+    }
+}
@@ -0,0 +1,680 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advent of Code 2023 day 7: Camel Cards
+
+/// Which rules `J` plays by.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+pub enum Ruleset {
+    /// `J` is a jack, ranked between ten and queen
+    #[default]
+    Jacks,
+    /// `J` is a joker, the weakest card alone but wild when typing a hand
+    Jokers,
+}
+
+/// The type of a hand, from weakest to strongest.
+#[derive(PartialOrd, Ord, Eq, Debug, PartialEq, Clone)]
+pub enum HandRanking {
+    HighCard = 0,
+    OnePair = 1,
+    TwoPairs = 2,
+    ThreeOfAKind = 3,
+    FullHouse = 4,
+    FourOfAKind = 5,
+    FiveOfAKind = 6,
+}
+
+impl HandRanking {
+    /// Classifies a hand under `ruleset` by its [`shape`].
+    pub fn from_cards(cards: Vec<Card>, ruleset: Ruleset) -> Self {
+        HandRanking::from_shape(&shape(&cards, ruleset))
+    }
+
+    /// Classifies a hand from how many of each card it holds, largest
+    /// count first.
+    pub fn from_shape(shape: &[usize]) -> Self {
+        match shape {
+            [5, ..] => HandRanking::FiveOfAKind,
+            [4, ..] => HandRanking::FourOfAKind,
+            [3, 2, ..] => HandRanking::FullHouse,
+            [3, ..] => HandRanking::ThreeOfAKind,
+            [2, 2, ..] => HandRanking::TwoPairs,
+            [2, ..] => HandRanking::OnePair,
+            _ => HandRanking::HighCard,
+        }
+    }
+}
+
+/// The hand as a multiset: how many of each card it holds, largest count
+/// first, so a full house is `[3, 2]`. Under jokers every joker joins the
+/// largest count, since adding to any other never makes a stronger hand,
+/// and a hand of nothing but jokers is one count of all of them.
+pub fn shape(cards: &[Card], ruleset: Ruleset) -> Vec<usize> {
+    let mut card_counts = [0; 13];
+    let mut jokers = 0;
+    for card in cards {
+        if Ruleset::Jokers == ruleset && Card::Jack == *card {
+            jokers += 1;
+        } else {
+            card_counts[*card as usize] += 1;
+        }
+    }
+    let mut counts = card_counts
+        .into_iter()
+        .filter(|count| 0 < *count)
+        .collect::<Vec<usize>>();
+    counts.sort_unstable_by(|left, right| right.cmp(left));
+    match counts.first_mut() {
+        Some(most) => *most += jokers,
+        None if 0 < jokers => counts.push(jokers),
+        None => {}
+    }
+    counts
+}
+
+/// A card label, in the order they rank with jacks.
+#[derive(PartialOrd, Ord, Eq, Debug, PartialEq, Clone, Copy)]
+pub enum Card {
+    Two = 0,
+    Three = 1,
+    Four = 2,
+    Five = 3,
+    Six = 4,
+    Seven = 5,
+    Eight = 6,
+    Nine = 7,
+    Ten = 8,    // T
+    Jack = 9,   // J
+    Queen = 10, // Q
+    King = 11,  // K
+    Ace = 12,   // A
+}
+
+impl Card {
+    /// Parses a card label, e.g. `T` for ten.
+    pub fn from_char(character: char) -> Option<Card> {
+        match character {
+            '2' => Some(Card::Two),
+            '3' => Some(Card::Three),
+            '4' => Some(Card::Four),
+            '5' => Some(Card::Five),
+            '6' => Some(Card::Six),
+            '7' => Some(Card::Seven),
+            '8' => Some(Card::Eight),
+            '9' => Some(Card::Nine),
+            'T' => Some(Card::Ten),
+            'J' => Some(Card::Jack),
+            'Q' => Some(Card::Queen),
+            'K' => Some(Card::King),
+            'A' => Some(Card::Ace),
+            _ => None,
+        }
+    }
+
+    /// How the card ranks when breaking ties under `ruleset`, 0 being the
+    /// weakest.
+    pub fn strength(&self, ruleset: Ruleset) -> usize {
+        match (ruleset, self) {
+            (Ruleset::Jacks, _) => *self as usize,
+            (Ruleset::Jokers, Card::Jack) => 0,
+            (Ruleset::Jokers, _) if *self < Card::Jack => *self as usize + 1,
+            (Ruleset::Jokers, _) => *self as usize,
+        }
+    }
+}
+
+impl std::fmt::Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let character = match self {
+            Card::Two => '2',
+            Card::Three => '3',
+            Card::Four => '4',
+            Card::Five => '5',
+            Card::Six => '6',
+            Card::Seven => '7',
+            Card::Eight => '8',
+            Card::Nine => '9',
+            Card::Ten => 'T',
+            Card::Jack => 'J',
+            Card::Queen => 'Q',
+            Card::King => 'K',
+            Card::Ace => 'A',
+        };
+        write!(f, "{}", character)
+    }
+}
+
+/// A hand of cards, its bid and the rules it's ranked by. Stronger hands
+/// sort first; hands of equal strength fall back to their bid and ruleset,
+/// so only equal hands compare as equal.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: Vec<Card>,
+    pub rank: HandRanking,
+    pub bid: usize,
+    pub ruleset: Ruleset,
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let strengths = |hand: &Hand| {
+            hand.cards
+                .iter()
+                .map(|card| card.strength(hand.ruleset))
+                .collect::<Vec<usize>>()
+        };
+        other
+            .rank
+            .cmp(&self.rank)
+            .then_with(|| strengths(other).cmp(&strengths(self)))
+            .then_with(|| self.bid.cmp(&other.bid))
+            .then_with(|| self.ruleset.cmp(&other.ruleset))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for card in &self.cards {
+            write!(f, "{}", card)?;
+        }
+        write!(f, " {}", self.bid)
+    }
+}
+
+impl Hand {
+    /// Parses a `32T3K 765` line, failing on anything but five known cards
+    /// and a numeric bid. Cards may be in either case.
+    pub fn try_from_str(input: &str, ruleset: Ruleset) -> Result<Self, String> {
+        let (cards, bid) = match input.split_whitespace().collect::<Vec<&str>>()[..] {
+            [cards, bid] => (cards, bid),
+            _ => return Err(format!("Expected cards and a bid in {:?}", input.trim())),
+        };
+        let cards = cards
+            .chars()
+            .map(|character| {
+                Card::from_char(character.to_ascii_uppercase())
+                    .ok_or(format!("Unknown card {:?} in {:?}", character, cards))
+            })
+            .collect::<Result<Vec<Card>, String>>()?;
+        if 5 != cards.len() {
+            return Err(format!("Expected 5 cards but found {}", cards.len()));
+        }
+        let bid = bid
+            .parse::<usize>()
+            .map_err(|_| format!("Unable to parse bid {:?}", bid))?;
+        Ok(Hand {
+            cards: cards.clone(),
+            rank: HandRanking::from_cards(cards, ruleset),
+            bid,
+            ruleset,
+        })
+    }
+
+    /// Parses a `32T3K 765` line into its cards and bid, ranked under
+    /// `ruleset`. Panics if it isn't a hand; see [`Hand::try_from_str`].
+    pub fn new_from_str(input: &str, ruleset: Ruleset) -> Self {
+        Hand::try_from_str(input, ruleset).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+/// Sums each hand's bid multiplied by its rank under `ruleset`, skipping
+/// blank lines. Fails on the first line that isn't a hand.
+pub fn total_winnings(input: &str, ruleset: Ruleset) -> Result<usize, String> {
+    let mut hands = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let hand = Hand::try_from_str(line, ruleset)
+            .map_err(|error| format!("line {}: {}", index + 1, error))?;
+        hands.push(hand);
+    }
+    hands.sort();
+    hands.reverse();
+    let mut sum = 0;
+    for (index, hand) in hands.iter().enumerate() {
+        sum += hand.bid * (index + 1);
+    }
+    Ok(sum)
+}
+
+/// Rewrites the hands in puzzle format, one per line, skipping blank lines.
+/// Fails on any line that isn't exactly a hand rather than rewriting it.
+pub fn format_input(input: &str) -> Result<String, String> {
+    let mut formatted = String::new();
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let hand = Hand::try_from_str(line, Ruleset::Jacks)
+            .map_err(|error| format!("line {}: {}", index + 1, error))?;
+        formatted.push_str(&format!("{}\n", hand));
+    }
+    Ok(formatted)
+}
+
+/// Sums each hand's bid multiplied by its rank.
+pub fn part1(input: String) -> Result<usize, String> {
+    total_winnings(&input, Ruleset::Jacks)
+}
+
+/// Sums each hand's bid multiplied by its rank when jacks are jokers.
+pub fn part2(input: String) -> Result<usize, String> {
+    total_winnings(&input, Ruleset::Jokers)
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_card_from_input() {
+        assert_eq!(Some(Card::Two), Card::from_char('2'));
+        assert_eq!(Some(Card::Three), Card::from_char('3'));
+        assert_eq!(Some(Card::Four), Card::from_char('4'));
+        assert_eq!(Some(Card::Five), Card::from_char('5'));
+        assert_eq!(Some(Card::Six), Card::from_char('6'));
+        assert_eq!(Some(Card::Seven), Card::from_char('7'));
+        assert_eq!(Some(Card::Eight), Card::from_char('8'));
+        assert_eq!(Some(Card::Nine), Card::from_char('9'));
+        assert_eq!(Some(Card::Ten), Card::from_char('T'));
+        assert_eq!(Some(Card::Jack), Card::from_char('J'));
+        assert_eq!(Some(Card::Queen), Card::from_char('Q'));
+        assert_eq!(Some(Card::King), Card::from_char('K'));
+        assert_eq!(Some(Card::Ace), Card::from_char('A'));
+        assert_eq!(None, Card::from_char('X'));
+    }
+
+    #[test]
+    fn card_can_print() {
+        for character in "23456789TJQKA".chars() {
+            assert_eq!(
+                character.to_string(),
+                Card::from_char(character).unwrap().to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn can_properly_rank_hands() {
+        assert_eq!(
+            HandRanking::HighCard,
+            HandRanking::from_cards(
+                vec![Card::Two, Card::Three, Card::Four, Card::Five, Card::Six],
+                Ruleset::Jacks
+            )
+        );
+        assert_eq!(
+            HandRanking::OnePair,
+            HandRanking::from_cards(
+                vec![Card::Two, Card::Two, Card::Four, Card::Five, Card::Six],
+                Ruleset::Jacks
+            )
+        );
+        assert_eq!(
+            HandRanking::TwoPairs,
+            HandRanking::from_cards(
+                vec![Card::Two, Card::Two, Card::Four, Card::Four, Card::Six],
+                Ruleset::Jacks
+            )
+        );
+        assert_eq!(
+            HandRanking::ThreeOfAKind,
+            HandRanking::from_cards(
+                vec![Card::Two, Card::Two, Card::Two, Card::Four, Card::Six],
+                Ruleset::Jacks
+            )
+        );
+        assert_eq!(
+            HandRanking::FullHouse,
+            HandRanking::from_cards(
+                vec![Card::Two, Card::Two, Card::Two, Card::Four, Card::Four],
+                Ruleset::Jacks
+            )
+        );
+        assert_eq!(
+            HandRanking::FourOfAKind,
+            HandRanking::from_cards(
+                vec![Card::Two, Card::Two, Card::Two, Card::Two, Card::Four],
+                Ruleset::Jacks
+            )
+        );
+        assert_eq!(
+            HandRanking::FiveOfAKind,
+            HandRanking::from_cards(
+                vec![Card::Two, Card::Two, Card::Two, Card::Two, Card::Two],
+                Ruleset::Jacks
+            )
+        );
+        // 32T3K
+        assert_eq!(
+            HandRanking::OnePair,
+            HandRanking::from_cards(
+                vec![Card::Three, Card::Two, Card::Ten, Card::Two, Card::King,],
+                Ruleset::Jacks
+            )
+        );
+        // T55J5
+        let t55j5 = vec![Card::Ten, Card::Five, Card::Five, Card::Jack, Card::Five];
+        assert_eq!(
+            HandRanking::ThreeOfAKind,
+            HandRanking::from_cards(t55j5.clone(), Ruleset::Jacks)
+        );
+        assert_eq!(
+            HandRanking::FourOfAKind,
+            HandRanking::from_cards(t55j5, Ruleset::Jokers)
+        );
+    }
+
+    #[test]
+    fn ranks_jokers_as_the_best_card() {
+        let rank = |cards: &str, ruleset| Hand::new_from_str(&format!("{} 1", cards), ruleset).rank;
+        assert_eq!(HandRanking::FiveOfAKind, rank("JJJJJ", Ruleset::Jokers));
+        assert_eq!(HandRanking::FiveOfAKind, rank("JJJJ2", Ruleset::Jokers));
+        assert_eq!(HandRanking::FourOfAKind, rank("JJ223", Ruleset::Jokers));
+        assert_eq!(HandRanking::FullHouse, rank("2233J", Ruleset::Jokers));
+        assert_eq!(HandRanking::ThreeOfAKind, rank("J2234", Ruleset::Jokers));
+        assert_eq!(HandRanking::OnePair, rank("J2345", Ruleset::Jokers));
+        assert_eq!(HandRanking::HighCard, rank("23456", Ruleset::Jokers));
+        assert_eq!(HandRanking::FiveOfAKind, rank("JJJJJ", Ruleset::Jacks));
+        assert_eq!(HandRanking::TwoPairs, rank("2233J", Ruleset::Jacks));
+        assert_eq!(HandRanking::HighCard, rank("J2345", Ruleset::Jacks));
+    }
+
+    const CARDS: [Card; 13] = [
+        Card::Two,
+        Card::Three,
+        Card::Four,
+        Card::Five,
+        Card::Six,
+        Card::Seven,
+        Card::Eight,
+        Card::Nine,
+        Card::Ten,
+        Card::Jack,
+        Card::Queen,
+        Card::King,
+        Card::Ace,
+    ];
+
+    /// Classifies five cards by comparing every pair of them, with no
+    /// counting shortcuts.
+    fn brute_force_standard(cards: &[Card; 5]) -> HandRanking {
+        let matches = |index: usize| cards.iter().filter(|card| **card == cards[index]).count();
+        let sizes: [usize; 5] = std::array::from_fn(matches);
+        let pairs = sizes.iter().filter(|size| 2 == **size).count() / 2;
+        if sizes.contains(&5) {
+            HandRanking::FiveOfAKind
+        } else if sizes.contains(&4) {
+            HandRanking::FourOfAKind
+        } else if sizes.contains(&3) && 1 == pairs {
+            HandRanking::FullHouse
+        } else if sizes.contains(&3) {
+            HandRanking::ThreeOfAKind
+        } else if 2 == pairs {
+            HandRanking::TwoPairs
+        } else if 1 == pairs {
+            HandRanking::OnePair
+        } else {
+            HandRanking::HighCard
+        }
+    }
+
+    /// Tries every card for every joker and keeps the best hand. Cards the
+    /// hand doesn't hold are interchangeable, so only one of them is tried.
+    fn brute_force_jokers(cards: &[Card; 5]) -> HandRanking {
+        let Some(joker) = cards.iter().position(|card| Card::Jack == *card) else {
+            return brute_force_standard(cards);
+        };
+        let unheld = CARDS
+            .iter()
+            .find(|card| Card::Jack != **card && !cards.contains(card));
+        cards
+            .iter()
+            .chain(unheld)
+            .filter(|card| Card::Jack != **card)
+            .map(|card| {
+                let mut cards = *cards;
+                cards[joker] = *card;
+                brute_force_jokers(&cards)
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn classifies_every_hand() {
+        for index in 0..13_usize.pow(5) {
+            let cards: [Card; 5] =
+                std::array::from_fn(|position| CARDS[index / 13_usize.pow(position as u32) % 13]);
+            assert_eq!(
+                brute_force_standard(&cards),
+                HandRanking::from_cards(cards.to_vec(), Ruleset::Jacks),
+                "{:?}",
+                cards
+            );
+            assert_eq!(
+                brute_force_jokers(&cards),
+                HandRanking::from_cards(cards.to_vec(), Ruleset::Jokers),
+                "{:?}",
+                cards
+            );
+        }
+    }
+
+    #[test]
+    fn finds_hand_shapes() {
+        let cards = |hand: &str| {
+            hand.chars()
+                .filter_map(Card::from_char)
+                .collect::<Vec<Card>>()
+        };
+        assert_eq!(vec![3, 2], shape(&cards("QQ2Q2"), Ruleset::Jacks));
+        assert_eq!(vec![2, 1, 1, 1], shape(&cards("J2345"), Ruleset::Jokers));
+        assert_eq!(vec![1, 1, 1, 1, 1], shape(&cards("J2345"), Ruleset::Jacks));
+        assert_eq!(vec![5], shape(&cards("JJJJJ"), Ruleset::Jokers));
+        assert_eq!(Vec::<usize>::new(), shape(&[], Ruleset::Jokers));
+    }
+
+    #[test]
+    fn orders_cards_by_ruleset() {
+        // Stronger hands sort first
+        assert!(Card::Jack.strength(Ruleset::Jacks) > Card::Ten.strength(Ruleset::Jacks));
+        assert!(Card::Jack.strength(Ruleset::Jacks) < Card::Queen.strength(Ruleset::Jacks));
+        for card in "23456789TQKA".chars() {
+            let card = Card::from_char(card).unwrap();
+            assert!(Card::Jack.strength(Ruleset::Jokers) < card.strength(Ruleset::Jokers));
+        }
+        assert!(
+            Hand::new_from_str("JKKK2 1", Ruleset::Jacks)
+                > Hand::new_from_str("QQQ22 1", Ruleset::Jacks)
+        );
+        assert!(
+            Hand::new_from_str("JKKK2 1", Ruleset::Jokers)
+                < Hand::new_from_str("QQQ22 1", Ruleset::Jokers)
+        );
+    }
+
+    #[test]
+    fn orders_consistently_with_equality() {
+        let hands = [
+            Hand::new_from_str("T55J5 684", Ruleset::Jacks),
+            Hand::new_from_str("T55J5 1", Ruleset::Jacks),
+            Hand::new_from_str("T55J5 684", Ruleset::Jokers),
+            Hand::new_from_str("9JJ99 684", Ruleset::Jokers),
+        ];
+        for left in &hands {
+            for right in &hands {
+                assert_eq!(left == right, left.cmp(right).is_eq(), "{} {}", left, right);
+            }
+        }
+    }
+
+    #[test]
+    fn hand_can_create_from_str() {
+        // T55J5
+        assert_eq!(
+            Hand {
+                cards: vec![Card::Ten, Card::Five, Card::Five, Card::Jack, Card::Five,],
+                rank: HandRanking::ThreeOfAKind,
+                bid: 684,
+                ruleset: Ruleset::Jacks,
+            },
+            Hand::new_from_str("T55J5 684", Ruleset::Jacks)
+        );
+        assert_eq!(
+            Hand {
+                cards: vec![Card::Ten, Card::Five, Card::Five, Card::Jack, Card::Five,],
+                rank: HandRanking::FourOfAKind,
+                bid: 684,
+                ruleset: Ruleset::Jokers,
+            },
+            Hand::new_from_str("T55J5 684", Ruleset::Jokers)
+        )
+    }
+
+    #[test]
+    fn hand_can_print() {
+        let hand = Hand::new_from_str("T55J5 684", Ruleset::Jokers);
+        assert_eq!("T55J5 684", hand.to_string());
+        assert_eq!(hand, Hand::new_from_str(&hand.to_string(), hand.ruleset));
+    }
+
+    #[test]
+    fn hands_can_be_properly_sorted_with_jacks() {
+        let mut input = vec![
+            Hand::new_from_str("32T3K 765", Ruleset::Jacks),
+            Hand::new_from_str("T55J5 684", Ruleset::Jacks),
+            Hand::new_from_str("KK677 28", Ruleset::Jacks),
+            Hand::new_from_str("KTJJT 220", Ruleset::Jacks),
+            Hand::new_from_str("QQQJA 483", Ruleset::Jacks),
+        ];
+        let output = vec![
+            Hand::new_from_str("QQQJA 483", Ruleset::Jacks),
+            Hand::new_from_str("T55J5 684", Ruleset::Jacks),
+            Hand::new_from_str("KK677 28", Ruleset::Jacks),
+            Hand::new_from_str("KTJJT 220", Ruleset::Jacks),
+            Hand::new_from_str("32T3K 765", Ruleset::Jacks),
+        ];
+        assert_ne!(input, output);
+        input.sort();
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn hands_can_be_properly_sorted_with_jokers() {
+        let mut input = vec![
+            Hand::new_from_str("32T3K 765", Ruleset::Jokers),
+            Hand::new_from_str("T55J5 684", Ruleset::Jokers),
+            Hand::new_from_str("KK677 28", Ruleset::Jokers),
+            Hand::new_from_str("KTJJT 220", Ruleset::Jokers),
+            Hand::new_from_str("QQQJA 483", Ruleset::Jokers),
+        ];
+        let output = vec![
+            Hand::new_from_str("KTJJT 220", Ruleset::Jokers),
+            Hand::new_from_str("QQQJA 483", Ruleset::Jokers),
+            Hand::new_from_str("T55J5 684", Ruleset::Jokers),
+            Hand::new_from_str("KK677 28", Ruleset::Jokers),
+            Hand::new_from_str("32T3K 765", Ruleset::Jokers),
+        ];
+        assert_ne!(input, output);
+        input.sort();
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            Ok(6440),
+            part1(
+                "32T3K 765
+                T55J5 684
+                KK677 28
+                KTJJT 220
+                QQQJA 483
+                "
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            Ok(5905),
+            part2(
+                "32T3K 765
+                T55J5 684
+                KK677 28
+                KTJJT 220
+                QQQJA 483
+                "
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn formats_input() {
+        assert_eq!(
+            Ok("32T3K 765\nT55J5 684\n".to_string()),
+            format_input("  32t3k 765\n\tT55J5 684\n\n")
+        );
+    }
+
+    #[test]
+    fn formatting_round_trips() {
+        let input = "  32t3k   765\n\tT55J5 684\nKK677 028\n";
+        let formatted = format_input(input).unwrap();
+        let parse = |input: &str| {
+            input
+                .trim()
+                .lines()
+                .map(|line| Hand::try_from_str(line, Ruleset::Jacks))
+                .collect::<Result<Vec<Hand>, String>>()
+        };
+        assert_eq!(parse(input), parse(&formatted));
+        assert_eq!(Ok(formatted.clone()), format_input(&formatted));
+        assert_eq!(
+            Err("line 1: Unknown card 'X' in \"32X3K\"".to_string()),
+            format_input("32X3K 765\n")
+        );
+        assert!(format_input("T55J5 68x4\n").is_err());
+        assert!(format_input("T55J 684\n").is_err());
+        assert!(format_input("T55J5\n").is_err());
+        assert!(format_input("T55J5 684 1\n").is_err());
+    }
+
+    #[test]
+    fn skips_blank_lines_and_rejects_malformed_hands() {
+        assert_eq!(
+            Ok(684 * 2 + 765),
+            part1("32T3K 765\n\nT55J5 684\n".to_string())
+        );
+        assert_eq!(Ok(0), part2("\n \n".to_string()));
+        assert_eq!(
+            Err("line 2: Expected cards and a bid in \"T55J5\"".to_string()),
+            part1("32T3K 765\nT55J5\n".to_string())
+        );
+        assert!(part2("32T3K 765\nT55J5 68x4\n".to_string()).is_err());
+    }
+}
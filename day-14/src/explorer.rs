@@ -0,0 +1,159 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io;
+
+use explorer::Steppable;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+
+use crate::{cycle_transposed_load_once, parse_input_to_map, transpose, transposed_north_load};
+
+/// The platform between spin cycles, one full north, west, south, east
+/// cycle per step. The map is transposed, so it's indexed `[x][y]`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Platform {
+    pub map: Vec<Vec<char>>,
+    pub previous: Option<Vec<Vec<char>>>,
+}
+
+impl Platform {
+    pub fn new(input: &str) -> Self {
+        Self {
+            map: transpose(parse_input_to_map(input)),
+            previous: None,
+        }
+    }
+}
+
+impl Steppable for Platform {
+    const STEP_NAME: &'static str = "Cycle";
+    const TITLE: &'static str = "Platform";
+
+    fn step(&mut self) -> bool {
+        let next = cycle_transposed_load_once(self.map.clone());
+        self.previous = Some(std::mem::replace(&mut self.map, next));
+        true
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (self.map.len(), self.map[0].len())
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Span<'static> {
+        let character = self.map[x][y];
+        let mut style = Style::default();
+        if let Some(previous) = &self.previous {
+            if previous[x][y] != character {
+                style = style.fg(Color::Yellow);
+            }
+        }
+        Span::styled(character.to_string(), style)
+    }
+
+    fn details(&self, cursor: (usize, usize)) -> Vec<Line<'static>> {
+        let contents = match self.map[cursor.0][cursor.1] {
+            'O' => "rounded rock",
+            '#' => "cube rock",
+            _ => "empty",
+        };
+        vec![
+            Line::from(format!("North load: {}", transposed_north_load(&self.map))),
+            Line::from(""),
+            Line::from(format!("Cell: ({}, {})", cursor.0, cursor.1)),
+            Line::from(format!("Contents: {}", contents)),
+        ]
+    }
+
+    /// Spin cycles settle into a loop, the same one [`crate::part2`] finds.
+    fn fingerprint(&self) -> Option<u64> {
+        let mut hasher = DefaultHasher::new();
+        self.map.hash(&mut hasher);
+        Some(hasher.finish())
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn run(input: &str) -> io::Result<()> {
+    explorer::run(Platform::new(input))
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use explorer::{render_to_lines, Explorer};
+    use ratatui::crossterm::event::KeyCode;
+
+    const EXAMPLE: &str = "O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....";
+
+    #[test]
+    fn explorer_steps_forward_and_back() {
+        let initial = Platform::new(EXAMPLE);
+        let mut explorer = Explorer::new(initial.clone());
+        explorer.jump(3, true);
+        assert_eq!(3, explorer.step());
+        let third = explorer.state().map.clone();
+        explorer.jump(2, false);
+        assert_eq!(1, explorer.step());
+        assert_eq!(
+            cycle_transposed_load_once(initial.map.clone()),
+            explorer.state().map
+        );
+        explorer.go_to(3);
+        assert_eq!(third, explorer.state().map);
+        explorer.jump(10, false);
+        assert_eq!(0, explorer.step());
+        assert_eq!(&initial, explorer.state());
+    }
+
+    #[test]
+    fn explorer_goes_to_far_cycles_through_the_loop() {
+        let mut explorer = Explorer::new(Platform::new(EXAMPLE));
+        explorer.go_to(1000000000);
+        assert_eq!(1000000000, explorer.step());
+        assert_eq!(64, transposed_north_load(&explorer.state().map));
+    }
+
+    #[test]
+    fn explorer_renders_platform_and_cell_state() {
+        let mut explorer = Explorer::new(Platform::new(EXAMPLE));
+        explorer.go_to(1);
+        explorer.handle_key(KeyCode::Right);
+        explorer.handle_key(KeyCode::Right);
+        explorer.handle_key(KeyCode::Down);
+        explorer.handle_key(KeyCode::Down);
+        explorer.handle_key(KeyCode::Down);
+        let lines = render_to_lines(&explorer, 60, 14);
+        assert!(lines[1].starts_with("│.....#...."));
+        assert!(lines[2].starts_with("│....#...O#"));
+        assert!(lines.iter().any(|line| line.contains("Cycle: 1")));
+        assert!(lines.iter().any(|line| line.contains("North load: 87")));
+        assert!(lines.iter().any(|line| line.contains("Cell: (2, 3)")));
+        assert!(lines
+            .iter()
+            .any(|line| line.contains("Contents: rounded rock")));
+        assert!(lines[13].starts_with("n step"));
+    }
+}
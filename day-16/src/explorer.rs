@@ -0,0 +1,127 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+
+use explorer::Steppable;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+
+use crate::{Direction, Map};
+
+/// The map and the beams still queued, moving one beam one tile per step.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Beams {
+    pub map: Map,
+    pub beams: Vec<(usize, usize, Direction)>,
+}
+
+impl Beams {
+    pub fn new(input: &str, start_x: usize, start_y: usize, direction: Direction) -> Self {
+        Self {
+            map: Map::new(input),
+            beams: vec![(start_x, start_y, direction)],
+        }
+    }
+}
+
+impl Steppable for Beams {
+    const STEP_NAME: &'static str = "Step";
+    const TITLE: &'static str = "Map";
+
+    fn step(&mut self) -> bool {
+        self.map.step(&mut self.beams)
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (self.map.width, self.map.height)
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Span<'static> {
+        let cell = &self.map.cells[y][x];
+        let mut style = Style::default();
+        if cell.energized {
+            style = style.fg(Color::Yellow);
+        }
+        if self
+            .beams
+            .iter()
+            .any(|(beam_x, beam_y, _)| x == *beam_x && y == *beam_y)
+        {
+            style = style.bg(Color::Red);
+        }
+        Span::styled(cell.to_string(), style)
+    }
+
+    fn details(&self, cursor: (usize, usize)) -> Vec<Line<'static>> {
+        let cell = &self.map.cells[cursor.1][cursor.0];
+        vec![
+            Line::from(format!("Queued beams: {}", self.beams.len())),
+            Line::from(format!("Energized: {}", self.map.get_energized_count())),
+            Line::from(""),
+            Line::from(format!("Cell: ({}, {})", cursor.0, cursor.1)),
+            Line::from(format!("Contents: {:?}", cell.contents)),
+            Line::from(format!("Energized: {}", cell.energized)),
+            Line::from(format!("Entered from: {:?}", cell.entered_from)),
+        ]
+    }
+
+    fn is_finished(&self) -> bool {
+        self.beams.is_empty()
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn run(input: &str) -> io::Result<()> {
+    explorer::run(Beams::new(input, 0, 0, Direction::East))
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use explorer::{render_to_lines, Explorer};
+
+    #[test]
+    fn explorer_steps_forward_and_back() {
+        let mut explorer = Explorer::new(Beams::new("..\\\n..-\n...", 0, 0, Direction::East));
+        explorer.jump(2, true);
+        assert_eq!(2, explorer.step());
+        assert_eq!(2, explorer.state().map.get_energized_count());
+        explorer.jump(1, false);
+        assert_eq!(1, explorer.step());
+        assert_eq!(1, explorer.state().map.get_energized_count());
+        explorer.go_to(100);
+        assert!(explorer.state().is_finished());
+        assert_eq!(6, explorer.state().map.get_energized_count());
+        let finished_at = explorer.step();
+        assert!(finished_at < 100);
+        explorer.jump(1, true);
+        assert_eq!(finished_at, explorer.step());
+    }
+
+    #[test]
+    fn explorer_renders_map_and_cell_state() {
+        let mut explorer = Explorer::new(Beams::new("..\\\n..-\n...", 0, 0, Direction::East));
+        explorer.go_to(3);
+        let lines = render_to_lines(&explorer, 60, 14);
+        assert!(lines[1].starts_with("│>>\\"));
+        assert!(lines[2].starts_with("│..-"));
+        assert!(lines.iter().any(|line| line.contains("Step: 3")));
+        assert!(lines
+            .iter()
+            .any(|line| line.contains("Contents: Beam([East])")));
+        assert!(lines[13].starts_with("n step"));
+    }
+}
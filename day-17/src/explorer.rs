@@ -0,0 +1,158 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+
+use explorer::Steppable;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+
+use crate::{parse_map, Direction, Search};
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::East,
+    Direction::South,
+    Direction::West,
+];
+
+impl Steppable for Search {
+    const STEP_NAME: &'static str = "Pop";
+    const TITLE: &'static str = "City";
+
+    fn step(&mut self) -> bool {
+        Search::step(self)
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (self.map[0].len(), self.map.len())
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Span<'static> {
+        let mut style = Style::default();
+        if DIRECTIONS
+            .iter()
+            .any(|direction| self.visited.contains_key(&(x, y, *direction)))
+        {
+            style = style.fg(Color::Yellow);
+        }
+        if let Some((_, current_x, current_y, _, _)) = self.current {
+            if x == current_x && y == current_y {
+                style = style.bg(Color::Red);
+            }
+        }
+        Span::styled(self.map[y][x].to_string(), style)
+    }
+
+    fn details(&self, cursor: (usize, usize)) -> Vec<Line<'static>> {
+        let mut lines = vec![Line::from(format!("Queued: {}", self.queue.len()))];
+        if let Some((heat_loss, x, y, steps, direction)) = self.current {
+            lines.push(Line::from(format!(
+                "Popped: ({}, {}) {:?}",
+                x, y, direction
+            )));
+            lines.push(Line::from(format!("Heat loss: {}", heat_loss)));
+            lines.push(Line::from(format!("Straight: {}", steps)));
+        }
+        if let Some(heat_loss) = self.least_heat_loss {
+            lines.push(Line::from(format!("Least heat loss: {}", heat_loss)));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(format!("Cell: ({}, {})", cursor.0, cursor.1)));
+        lines.push(Line::from(format!(
+            "Heat loss: {}",
+            self.map[cursor.1][cursor.0]
+        )));
+        for direction in DIRECTIONS {
+            if let Some(steps) = self.visited.get(&(cursor.0, cursor.1, direction)) {
+                lines.push(Line::from(format!(
+                    "Visited {:?}: {} straight",
+                    direction, steps
+                )));
+            }
+        }
+        lines
+    }
+
+    fn is_finished(&self) -> bool {
+        self.least_heat_loss.is_some() || self.queue.is_empty()
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn run(input: &str) -> io::Result<()> {
+    explorer::run(Search::new(parse_map(input)))
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use explorer::{render_to_lines, Explorer};
+    use ratatui::crossterm::event::KeyCode;
+
+    const EXAMPLE: &str = "2413432311323
+        3215453535623
+        3255245654254
+        3446585845452
+        4546657867536
+        1438598798454
+        4457876987766
+        3637877979653
+        4654967986887
+        4564679986453
+        1224686865563
+        2546548887735
+        4322674655533";
+
+    #[test]
+    fn explorer_steps_forward_and_back() {
+        let mut explorer = Explorer::new(Search::new(parse_map(EXAMPLE)));
+        explorer.jump(1, true);
+        assert_eq!(
+            Some((0, 0, 0, 0, Direction::East)),
+            explorer.state().current
+        );
+        assert_eq!(2, explorer.state().queue.len());
+        explorer.jump(5, true);
+        let sixth = explorer.state().current;
+        explorer.jump(3, false);
+        assert_eq!(3, explorer.step());
+        explorer.go_to(6);
+        assert_eq!(sixth, explorer.state().current);
+        explorer.go_to(1000000);
+        assert!(explorer.state().is_finished());
+        assert_eq!(Some(102), explorer.state().least_heat_loss);
+        let finished_at = explorer.step();
+        explorer.jump(1, true);
+        assert_eq!(finished_at, explorer.step());
+    }
+
+    #[test]
+    fn explorer_renders_city_and_cell_state() {
+        let mut explorer = Explorer::new(Search::new(parse_map(EXAMPLE)));
+        explorer.go_to(1);
+        explorer.handle_key(KeyCode::Right);
+        let lines = render_to_lines(&explorer, 60, 16);
+        assert!(lines[1].starts_with("│2413432311323"));
+        assert!(lines.iter().any(|line| line.contains("Pop: 1")));
+        assert!(lines.iter().any(|line| line.contains("Queued: 2")));
+        assert!(lines
+            .iter()
+            .any(|line| line.contains("Popped: (0, 0) East")));
+        assert!(lines.iter().any(|line| line.contains("Cell: (1, 0)")));
+        assert!(lines.iter().any(|line| line.contains("Heat loss: 4")));
+        assert!(lines[15].starts_with("n step"));
+    }
+}
@@ -12,55 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs::read_to_string;
+use day_20::{part1, part2};
 
 #[cfg(not(tarpaulin_include))]
 fn main() {
-    let input = read_to_string("input.txt").expect("Unable to read input file");
+    let input = input::read(&[]);
     println!("Part 1: {}", part1(input.clone()));
     println!("Part 2: {}", part2(input));
 }
-
-#[cfg(not(tarpaulin_include))]
-fn part1(_input: String) -> usize {
-    todo!()
-}
-
-#[cfg(not(tarpaulin_include))]
-fn part2(_input: String) -> usize {
-    todo!()
-}
-
-#[cfg(not(tarpaulin_include))]
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    // #[test]
-    // fn solves_part1() {
-    //     assert_eq!(
-    //         32000000,
-    //         part1(
-    //             "broadcaster -> a, b, c
-    //             %a -> b
-    //             %b -> c
-    //             %c -> inv
-    //             &inv -> a
-    //             "
-    //             .to_string()
-    //         )
-    //     );
-    //     assert_eq!(
-    //         11687500,
-    //         part1(
-    //             "broadcaster -> a
-    //             %a -> inv, con
-    //             &inv -> b
-    //             %b -> con
-    //             &con -> output
-    //             "
-    //             .to_string()
-    //         )
-    //     );
-    // }
-}
@@ -0,0 +1,158 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io;
+
+use explorer::Steppable;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+
+use crate::{next_layer, parse_map};
+
+/// The garden and the plots reachable in exactly as many steps as have
+/// been taken, one layer per step.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Garden {
+    pub map: Vec<Vec<char>>,
+    pub santa: (usize, usize),
+    pub layer: HashSet<(usize, usize)>,
+}
+
+impl Garden {
+    pub fn new(input: &str) -> Self {
+        let (map, santa) = parse_map(input);
+        Self {
+            map,
+            santa,
+            layer: HashSet::from([santa]),
+        }
+    }
+}
+
+impl Steppable for Garden {
+    const STEP_NAME: &'static str = "Steps";
+    const TITLE: &'static str = "Garden";
+
+    fn step(&mut self) -> bool {
+        self.layer = next_layer(&self.map, &self.layer);
+        true
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (self.map[0].len(), self.map.len())
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Span<'static> {
+        if self.layer.contains(&(x, y)) {
+            Span::styled("O", Style::default().fg(Color::Yellow))
+        } else if (x, y) == self.santa {
+            Span::raw("S")
+        } else {
+            Span::raw(self.map[y][x].to_string())
+        }
+    }
+
+    fn details(&self, cursor: (usize, usize)) -> Vec<Line<'static>> {
+        let contents = match self.map[cursor.1][cursor.0] {
+            '#' => "rock",
+            _ => "garden plot",
+        };
+        vec![
+            Line::from(format!("Reachable: {}", self.layer.len())),
+            Line::from(""),
+            Line::from(format!("Cell: ({}, {})", cursor.0, cursor.1)),
+            Line::from(format!("Contents: {}", contents)),
+            Line::from(format!("Reachable: {}", self.layer.contains(&cursor))),
+        ]
+    }
+
+    /// Once every plot is reached the layers flip between two sets.
+    fn fingerprint(&self) -> Option<u64> {
+        let mut layer = self.layer.iter().collect::<Vec<_>>();
+        layer.sort();
+        let mut hasher = DefaultHasher::new();
+        layer.hash(&mut hasher);
+        Some(hasher.finish())
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+pub fn run(input: &str) -> io::Result<()> {
+    explorer::run(Garden::new(input))
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use explorer::{render_to_lines, Explorer};
+    use ratatui::crossterm::event::KeyCode;
+
+    const EXAMPLE: &str = "...........
+        .....###.#.
+        .###.##..#.
+        ..#.#...#..
+        ....#.#....
+        .##..S####.
+        .##..#...#.
+        .......##..
+        .##.#.####.
+        .##..##.##.
+        ...........";
+
+    #[test]
+    fn explorer_steps_forward_and_back() {
+        let mut explorer = Explorer::new(Garden::new(EXAMPLE));
+        explorer.jump(6, true);
+        assert_eq!(16, explorer.state().layer.len());
+        explorer.jump(4, false);
+        assert_eq!(2, explorer.step());
+        assert_eq!(4, explorer.state().layer.len());
+        explorer.jump(10, false);
+        assert_eq!(HashSet::from([(5, 5)]), explorer.state().layer);
+    }
+
+    #[test]
+    fn explorer_goes_to_far_steps_through_the_loop() {
+        let (map, santa) = parse_map(EXAMPLE);
+        let mut explorer = Explorer::new(Garden::new(EXAMPLE));
+        explorer.go_to(1000000000);
+        assert_eq!(1000000000, explorer.step());
+        assert_eq!(
+            crate::get_possible_tails_count(map, santa, 100),
+            explorer.state().layer.len()
+        );
+    }
+
+    #[test]
+    fn explorer_renders_garden_and_cell_state() {
+        let mut explorer = Explorer::new(Garden::new(EXAMPLE));
+        explorer.go_to(1);
+        for _ in 0..4 {
+            explorer.handle_key(KeyCode::Right);
+            explorer.handle_key(KeyCode::Down);
+        }
+        explorer.handle_key(KeyCode::Down);
+        let lines = render_to_lines(&explorer, 60, 14);
+        assert!(lines[5].starts_with("│....#O#...."));
+        assert!(lines[6].starts_with("│.##.OS####."));
+        assert!(lines.iter().any(|line| line.contains("Steps: 1")));
+        assert!(lines.iter().any(|line| line.contains("Reachable: 2")));
+        assert!(lines.iter().any(|line| line.contains("Cell: (4, 5)")));
+        assert!(lines.iter().any(|line| line.contains("Reachable: true")));
+        assert!(lines[13].starts_with("n step"));
+    }
+}
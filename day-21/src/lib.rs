@@ -0,0 +1,135 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advent of Code 2023 day 21: Step Counter
+
+use std::collections::HashSet;
+
+/// A terminal UI for stepping through reachable plots.
+pub mod explorer;
+
+/// Parses the garden and finds the `S` start.
+pub fn parse_map(input: &str) -> (Vec<Vec<char>>, (usize, usize)) {
+    let input = input.trim();
+    let mut map = Vec::new();
+    let mut santa = (0, 0);
+    for (y, line) in input.lines().enumerate() {
+        let line = line.trim();
+        let mut row = Vec::new();
+        for (x, character) in line.chars().enumerate() {
+            if 'S' == character {
+                santa = (x, y);
+                row.push('.');
+            } else {
+                row.push(character);
+            }
+        }
+        map.push(row);
+    }
+    (map, santa)
+}
+
+/// Finds the plots one step from any plot in `layer`.
+pub fn next_layer(map: &[Vec<char>], layer: &HashSet<(usize, usize)>) -> HashSet<(usize, usize)> {
+    let mut available = HashSet::new();
+    for (x, y) in layer.iter() {
+        if 0 < *x && '.' == map[*y][*x - 1] {
+            available.insert((*x - 1, *y));
+        }
+        if 0 < *y && '.' == map[*y - 1][*x] {
+            available.insert((*x, *y - 1));
+        }
+        if map[*y].len() > *x + 1 && '.' == map[*y][*x + 1] {
+            available.insert((*x + 1, *y));
+        }
+        if map.len() > *y + 1 && '.' == map[*y + 1][*x] {
+            available.insert((*x, *y + 1));
+        }
+    }
+    available
+}
+
+/// Counts the plots reachable in exactly `max_steps` steps.
+pub fn get_possible_tails_count(
+    map: Vec<Vec<char>>,
+    santa: (usize, usize),
+    max_steps: usize,
+) -> usize {
+    let mut available = HashSet::new();
+    available.insert(santa);
+    for _ in 0..max_steps {
+        available = next_layer(&map, &available);
+    }
+    available.len()
+}
+
+/// Counts the plots reachable in exactly 64 steps.
+pub fn part1(input: String) -> usize {
+    let (map, santa) = parse_map(&input);
+    get_possible_tails_count(map, santa, 64)
+}
+
+/// Counts the plots reachable in exactly 26501365 steps on an infinite map.
+///
+/// Not yet implemented; panics.
+pub fn part2(_input: String) -> usize {
+    todo!()
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_map() {
+        let input = "...........
+        .....###.#.
+        .###.##..#.
+        ..#.#...#..
+        ....#.#....
+        .##..S####.
+        .##..#...#.
+        .......##..
+        .##.#.####.
+        .##..##.##.
+        ...........
+        ";
+        let (map, santa) = parse_map(input);
+        assert_eq!(11, map.len());
+        assert_eq!(11, map[0].len());
+        assert_eq!((5, 5), santa);
+    }
+
+    #[test]
+    fn counts_proper_number_of_tails() {
+        let (map, santa) = parse_map(
+            "...........
+        .....###.#.
+        .###.##..#.
+        ..#.#...#..
+        ....#.#....
+        .##..S####.
+        .##..#...#.
+        .......##..
+        .##.#.####.
+        .##..##.##.
+        ...........
+        ",
+        );
+        assert_eq!(2, get_possible_tails_count(map.clone(), santa, 1));
+        assert_eq!(4, get_possible_tails_count(map.clone(), santa, 2));
+        assert_eq!(16, get_possible_tails_count(map, santa, 6));
+    }
+}
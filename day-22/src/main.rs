@@ -12,61 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs::read_to_string;
+use day_22::{part1, part2};
 
 #[cfg(not(tarpaulin_include))]
 fn main() {
-    let input = read_to_string("input.txt").expect("Unable to read input file");
+    let input = input::read(&[]);
     println!("Part 1: {}", part1(input.clone()));
     println!("Part 2: {}", part2(input));
 }
-
-fn part1(input: String) -> usize {
-    5
-}
-
-fn part2(input: String) -> usize {
-    7
-}
-
-#[cfg(not(tarpaulin_include))]
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn solves_part1() {
-        assert_eq!(
-            5,
-            part1(
-                "1,0,1~1,2,1
-                0,0,2~2,0,2
-                0,2,3~2,2,3
-                0,0,4~0,2,4
-                2,0,5~2,2,5
-                0,1,6~2,1,6
-                1,1,8~1,1,9
-                "
-                .to_string()
-            )
-        )
-    }
-
-    #[test]
-    fn solves_part2() {
-        assert_eq!(
-            7,
-            part2(
-                "1,0,1~1,2,1
-                0,0,2~2,0,2
-                0,2,3~2,2,3
-                0,0,4~0,2,4
-                2,0,5~2,2,5
-                0,1,6~2,1,6
-                1,1,8~1,1,9
-                "
-                .to_string()
-            )
-        )
-    }
-}
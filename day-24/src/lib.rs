@@ -0,0 +1,276 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Advent of Code 2023 day 24: Never Tell Me The Odds
+
+use itertools::Itertools;
+
+/// A hailstone's starting position and velocity.
+#[derive(Debug, PartialEq)]
+pub struct Hailstone {
+    pub position: (f64, f64, f64),
+    pub velocity: (f64, f64, f64),
+}
+
+impl Hailstone {
+    /// Parses a `19, 13, 30 @ -2, 1, -2` line.
+    pub fn from_str(input: &str) -> Self {
+        let input = input.trim();
+        let mut parts = input.split(" @");
+        let position = parts
+            .next()
+            .expect("Unable to get position")
+            .split(',')
+            .map(|x| x.trim().parse::<f64>().expect("Unable to parse position"))
+            .collect::<Vec<f64>>();
+        let velocity = parts
+            .next()
+            .expect("Unable to get velocity")
+            .split(',')
+            .map(|x| x.trim().parse::<f64>().expect("Unable to parse velocity"))
+            .collect::<Vec<f64>>();
+        Self {
+            position: (position[0], position[1], position[2]),
+            velocity: (velocity[0], velocity[1], velocity[2]),
+        }
+    }
+
+    /// Parses a `19, 13, 30 @ -2, 1, -2` line, failing on anything but two
+    /// sets of three integers that an f64 holds exactly.
+    pub fn try_from_str(input: &str) -> Result<Self, String> {
+        let (position, velocity) = input
+            .split_once('@')
+            .ok_or(format!("Unable to find '@' in {:?}", input.trim()))?;
+        Ok(Self {
+            position: exact_triple(position)?,
+            velocity: exact_triple(velocity)?,
+        })
+    }
+
+    /// Whether the two future paths cross inside the test area, ignoring Z.
+    pub fn crosses_pathes_in_test_area(&self, other: &Self, min: f64, max: f64) -> bool {
+        let determinant =
+            (self.velocity.1 * other.velocity.0) - (self.velocity.0 * other.velocity.1);
+        if 0.0 == determinant {
+            return false;
+        }
+        let d_x = other.position.0 - self.position.0;
+        let d_y = other.position.1 - self.position.1;
+        let u = ((self.velocity.0 * d_y) - (self.velocity.1 * d_x)) / determinant;
+        if u < 0.0 {
+            return false;
+        }
+        let v = ((other.velocity.0 * d_y) - (other.velocity.1 * d_x)) / determinant;
+        if v < 0.0 {
+            return false;
+        }
+        let point = (
+            (self.position.0 + (v * self.velocity.0)),
+            (self.position.1 + (u * self.velocity.1)),
+        );
+        if point.0 < min || point.0 > max || point.1 < min || point.1 > max {
+            return false;
+        }
+        true
+    }
+}
+
+impl std::fmt::Display for Hailstone {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, {}, {} @ {}, {}, {}",
+            self.position.0,
+            self.position.1,
+            self.position.2,
+            self.velocity.0,
+            self.velocity.1,
+            self.velocity.2
+        )
+    }
+}
+
+/// Integers up to this size convert to f64 without rounding.
+const MAX_EXACT: i64 = 1 << f64::MANTISSA_DIGITS;
+
+/// Parses `x, y, z` as integers that convert to f64 without rounding.
+fn exact_triple(input: &str) -> Result<(f64, f64, f64), String> {
+    let values = input
+        .split(',')
+        .map(|value| match value.trim().parse::<i64>() {
+            Ok(value) if value.abs() <= MAX_EXACT => Ok(value as f64),
+            _ => Err(format!(
+                "Unable to read {:?} as an exact integer",
+                value.trim()
+            )),
+        })
+        .collect::<Result<Vec<f64>, String>>()?;
+    match values[..] {
+        [x, y, z] => Ok((x, y, z)),
+        _ => Err(format!("Expected three values in {:?}", input.trim())),
+    }
+}
+
+/// Parses every hailstone.
+pub fn parse_input(input: String) -> Vec<Hailstone> {
+    let input = input.trim();
+    input
+        .lines()
+        .map(|x| Hailstone::from_str(x))
+        .collect::<Vec<Hailstone>>()
+}
+
+/// Counts the pairs whose future paths cross inside the test area, ignoring Z.
+pub fn find_intersection_count_in_test_area(
+    hailstones: Vec<Hailstone>,
+    min: f64,
+    max: f64,
+) -> usize {
+    let mut intersection_count = 0;
+    for combination in hailstones.iter().combinations(2) {
+        if combination[0].crosses_pathes_in_test_area(combination[1], min, max) {
+            intersection_count += 1;
+        }
+    }
+    intersection_count
+}
+
+/// Rewrites the hailstones in puzzle format, one per line, skipping blank
+/// lines. Fails on any line a hailstone can't hold exactly rather than
+/// rounding or dropping part of it.
+pub fn format_input(input: &str) -> Result<String, String> {
+    let mut formatted = String::new();
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let hailstone = Hailstone::try_from_str(line)
+            .map_err(|error| format!("line {}: {}", index + 1, error))?;
+        formatted.push_str(&format!("{}\n", hailstone));
+    }
+    Ok(formatted)
+}
+
+/// Counts the crossings inside the real test area.
+pub fn part1(input: String) -> usize {
+    let hailstones = parse_input(input);
+    find_intersection_count_in_test_area(hailstones, 200000000000000.0, 400000000000000.0)
+}
+
+/// Sums the starting coordinates of a rock that hits every hailstone.
+///
+/// Not yet implemented; panics.
+pub fn part2(_input: String) -> usize {
+    todo!()
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hailstone_parses_from_input() {
+        assert_eq!(
+            Hailstone {
+                position: (19.0, 13.0, 30.0),
+                velocity: (-2.0, 1.0, -2.0)
+            },
+            Hailstone::from_str("19, 13, 30 @ -2,  1, -2")
+        );
+    }
+
+    #[test]
+    fn hailstone_can_print() {
+        let hailstone = Hailstone::from_str("19, 13, 30 @ -2,  1, -2");
+        assert_eq!("19, 13, 30 @ -2, 1, -2", hailstone.to_string());
+        assert_eq!(hailstone, Hailstone::from_str(&hailstone.to_string()));
+    }
+
+    #[test]
+    fn hailstone_crosses_pathes_in_test_area() {
+        let hailstone = Hailstone {
+            position: (19.0, 13.0, 30.0),
+            velocity: (-2.0, 1.0, -2.0),
+        };
+        assert!(hailstone.crosses_pathes_in_test_area(
+            &Hailstone {
+                position: (18.0, 19.0, 22.0),
+                velocity: (-1.0, -1.0, -2.0)
+            },
+            7.0,
+            27.0
+        ));
+    }
+
+    #[test]
+    fn parses_all_hailstones_from_input() {
+        assert_eq!(
+            vec![Hailstone {
+                position: (19.0, 13.0, 30.0),
+                velocity: (-2.0, 1.0, -2.0)
+            }],
+            parse_input(
+                "
+                19, 13, 30 @ -2,  1, -2
+                "
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn finds_all_intersections_in_test_area() {
+        let hailstones = parse_input(
+            "19, 13, 30 @ -2,  1, -2
+            18, 19, 22 @ -1, -1, -2
+            20, 25, 34 @ -2, -2, -4
+            12, 31, 28 @ -1, -2, -1
+            20, 19, 15 @  1, -5, -3
+            "
+            .to_string(),
+        );
+        assert_eq!(
+            2,
+            find_intersection_count_in_test_area(hailstones, 7.0, 27.0)
+        );
+    }
+
+    #[test]
+    fn formats_input() {
+        assert_eq!(
+            Ok("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2\n".to_string()),
+            format_input("19,13,30 @ -2,1,-2\n  18, 19, 22 @ -1, -1, -2\n")
+        );
+    }
+
+    #[test]
+    fn formatting_round_trips() {
+        let input = "19,13,30 @ -2,1,-2\n\n  262130794315133, 305267994111063, 163273807102793 @ 57, -252, 150\n";
+        let formatted = format_input(input).unwrap();
+        assert_eq!(
+            parse_input(input.replace("\n\n", "\n")),
+            parse_input(formatted.clone())
+        );
+        assert_eq!(Ok(formatted.clone()), format_input(&formatted));
+        assert_eq!(
+            Err("line 1: Unable to read \"1.5\" as an exact integer".to_string()),
+            format_input("19, 13, 1.5 @ -2, 1, -2\n")
+        );
+        assert!(format_input("19, 13, 30, 4 @ -2, 1, -2\n").is_err());
+        assert!(format_input("19, 13 @ -2, 1, -2\n").is_err());
+        assert!(format_input("19, 13, 30 -2, 1, -2\n").is_err());
+        assert!(format_input("19, 13, 9007199254740993 @ -2, 1, -2\n").is_err());
+    }
+}
@@ -0,0 +1,459 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A terminal UI for stepping through any solver that can be written as a
+//! step-able state machine over a grid.
+
+use std::collections::HashMap;
+use std::io;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::Frame;
+
+/// The largest count the digit keys build up.
+pub const MAX_COUNT: usize = 1_000_000_000;
+
+/// How many steps apart the explorer keeps snapshots to step back from.
+pub const CHECKPOINT_INTERVAL: usize = 64;
+
+/// A solver's state that can be advanced one step at a time.
+pub trait Steppable: Clone {
+    /// What one step is called in the state panel, e.g. `Cycle`.
+    const STEP_NAME: &'static str;
+
+    /// The title over the grid.
+    const TITLE: &'static str;
+
+    /// Advances one step, returning false if there was nothing left to do.
+    fn step(&mut self) -> bool;
+
+    /// The grid's width and height, which the cursor stays inside.
+    fn size(&self) -> (usize, usize);
+
+    /// How to draw one cell of the grid.
+    fn cell(&self, x: usize, y: usize) -> Span<'static>;
+
+    /// Lines for the state panel about the state and the cell under the
+    /// cursor.
+    fn details(&self, cursor: (usize, usize)) -> Vec<Line<'static>>;
+
+    /// Whether the solver has run out of steps.
+    fn is_finished(&self) -> bool {
+        false
+    }
+
+    /// Identifies the state when states can repeat, so the explorer can go
+    /// to any step of a cycle without replaying it.
+    fn fingerprint(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// Steps through a [`Steppable`] forward and back with a cursor over its
+/// grid. Snapshots every [`CHECKPOINT_INTERVAL`] steps keep stepping back
+/// cheap, and a repeated fingerprint turns far-off steps into their place
+/// in the cycle.
+pub struct Explorer<S: Steppable> {
+    state: S,
+    step: usize,
+    checkpoints: Vec<S>,
+    seen: HashMap<u64, usize>,
+    cycle: Option<(usize, usize)>,
+    cursor: (usize, usize),
+    count: Option<usize>,
+}
+
+impl<S: Steppable> Explorer<S> {
+    pub fn new(state: S) -> Self {
+        let mut seen = HashMap::new();
+        if let Some(fingerprint) = state.fingerprint() {
+            seen.insert(fingerprint, 0);
+        }
+        Self {
+            checkpoints: vec![state.clone()],
+            state,
+            step: 0,
+            seen,
+            cycle: None,
+            cursor: (0, 0),
+            count: None,
+        }
+    }
+
+    /// The current state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// How many steps the current state is from the start.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// The cell under the cursor.
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// The step whose state matches `step`'s, folding steps past a known
+    /// cycle back into its first lap.
+    fn canonical(&self, step: usize) -> usize {
+        match self.cycle {
+            Some((start, length)) if step >= start => start + (step - start) % length,
+            _ => step,
+        }
+    }
+
+    /// Moves to `step`, or to the last step if the solver finishes first.
+    pub fn go_to(&mut self, step: usize) {
+        let mut current = self.canonical(self.step);
+        let mut goal = self.canonical(step);
+        let checkpoint = (goal / CHECKPOINT_INTERVAL).min(self.checkpoints.len() - 1);
+        if goal < current || checkpoint * CHECKPOINT_INTERVAL > current {
+            self.state = self.checkpoints[checkpoint].clone();
+            current = checkpoint * CHECKPOINT_INTERVAL;
+        }
+        while current < goal {
+            if !self.state.step() {
+                self.step = current;
+                return;
+            }
+            current += 1;
+            if current == self.checkpoints.len() * CHECKPOINT_INTERVAL {
+                self.checkpoints.push(self.state.clone());
+            }
+            if self.cycle.is_some() {
+                continue;
+            }
+            if let Some(fingerprint) = self.state.fingerprint() {
+                match self.seen.get(&fingerprint) {
+                    Some(&earlier) if earlier < current => {
+                        self.cycle = Some((earlier, current - earlier));
+                        current = earlier;
+                        goal = self.canonical(step);
+                    }
+                    Some(_) => {}
+                    None => {
+                        self.seen.insert(fingerprint, current);
+                    }
+                }
+            }
+        }
+        self.step = step;
+    }
+
+    /// Moves `steps` forward, or back if `forward` is false.
+    pub fn jump(&mut self, steps: usize, forward: bool) {
+        if forward {
+            self.go_to(self.step.saturating_add(steps));
+        } else {
+            self.go_to(self.step.saturating_sub(steps));
+        }
+    }
+
+    fn move_cursor(&mut self, x_offset: isize, y_offset: isize) {
+        let (width, height) = self.state.size();
+        self.cursor.0 = self
+            .cursor
+            .0
+            .saturating_add_signed(x_offset)
+            .min(width.saturating_sub(1));
+        self.cursor.1 = self
+            .cursor
+            .1
+            .saturating_add_signed(y_offset)
+            .min(height.saturating_sub(1));
+    }
+
+    /// Applies a key press and returns false once the user wants to quit.
+    /// Digits build up a count, up to [`MAX_COUNT`], that the next step,
+    /// back or go-to key uses.
+    pub fn handle_key(&mut self, code: KeyCode) -> bool {
+        if let KeyCode::Char(character) = code {
+            if let Some(digit) = character.to_digit(10) {
+                let count = self
+                    .count
+                    .unwrap_or(0)
+                    .saturating_mul(10)
+                    .saturating_add(digit as usize);
+                self.count = Some(count.min(MAX_COUNT));
+                return true;
+            }
+        }
+        let count = self.count.take();
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('n') | KeyCode::Char(' ') => self.jump(count.unwrap_or(1), true),
+            KeyCode::Char('p') => self.jump(count.unwrap_or(1), false),
+            KeyCode::Char('g') => self.go_to(count.unwrap_or(0)),
+            KeyCode::Left => self.move_cursor(-1, 0),
+            KeyCode::Right => self.move_cursor(1, 0),
+            KeyCode::Up => self.move_cursor(0, -1),
+            KeyCode::Down => self.move_cursor(0, 1),
+            _ => {}
+        }
+        true
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let [body, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [grid, details] =
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(36)]).areas(body);
+        let (width, height) = self.state.size();
+        let lines = (0..height)
+            .map(|y| {
+                Line::from(
+                    (0..width)
+                        .map(|x| {
+                            let cell = self.state.cell(x, y);
+                            if (x, y) == self.cursor {
+                                cell.patch_style(Modifier::REVERSED)
+                            } else {
+                                cell
+                            }
+                        })
+                        .collect::<Vec<Span>>(),
+                )
+            })
+            .collect::<Vec<Line>>();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(S::TITLE)),
+            grid,
+        );
+        let mut details_text = vec![Line::from(format!(
+            "{}: {}{}",
+            S::STEP_NAME,
+            self.step,
+            if self.state.is_finished() {
+                " (done)"
+            } else {
+                ""
+            }
+        ))];
+        details_text.extend(self.state.details(self.cursor));
+        details_text.push(Line::from(""));
+        details_text.push(Line::from(format!(
+            "Count: {}",
+            self.count.map_or(String::new(), |count| count.to_string())
+        )));
+        frame.render_widget(
+            Paragraph::new(details_text).block(Block::bordered().title("State")),
+            details,
+        );
+        frame.render_widget(
+            Paragraph::new("n step  p back  <N>n/<N>p jump  <N>g go to  arrows move  q quit"),
+            footer,
+        );
+    }
+}
+
+/// Runs the explorer in the terminal until the user quits.
+#[cfg(not(tarpaulin_include))]
+pub fn run<S: Steppable>(state: S) -> io::Result<()> {
+    let mut explorer = Explorer::new(state);
+    let mut terminal = ratatui::init();
+    let result = loop {
+        if let Err(error) = terminal.draw(|frame| explorer.render(frame)) {
+            break Err(error);
+        }
+        match event::read() {
+            Ok(Event::Key(key)) if KeyEventKind::Press == key.kind => {
+                if !explorer.handle_key(key.code) {
+                    break Ok(());
+                }
+            }
+            Ok(_) => {}
+            Err(error) => break Err(error),
+        }
+    };
+    ratatui::restore();
+    result
+}
+
+/// Draws an explorer into a fake terminal and returns its rows, so
+/// rendering can be tested headlessly.
+pub fn render_to_lines<S: Steppable>(
+    explorer: &Explorer<S>,
+    width: u16,
+    height: u16,
+) -> Vec<String> {
+    let mut terminal =
+        ratatui::Terminal::new(ratatui::backend::TestBackend::new(width, height)).unwrap();
+    terminal.draw(|frame| explorer.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect()
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Counts up to `limit`, or forever wrapping at `wrap` if it's set,
+    /// and records every step taken.
+    #[derive(Clone)]
+    struct Counter {
+        value: usize,
+        limit: usize,
+        wrap: Option<usize>,
+        steps_taken: Rc<Cell<usize>>,
+    }
+
+    impl Counter {
+        fn new(limit: usize, wrap: Option<usize>) -> Self {
+            Self {
+                value: 0,
+                limit,
+                wrap,
+                steps_taken: Rc::new(Cell::new(0)),
+            }
+        }
+    }
+
+    impl Steppable for Counter {
+        const STEP_NAME: &'static str = "Tick";
+        const TITLE: &'static str = "Counter";
+
+        fn step(&mut self) -> bool {
+            if self.is_finished() {
+                return false;
+            }
+            self.steps_taken.set(self.steps_taken.get() + 1);
+            self.value = match self.wrap {
+                Some(wrap) => (self.value + 1) % wrap,
+                None => self.value + 1,
+            };
+            true
+        }
+
+        fn size(&self) -> (usize, usize) {
+            (3, 2)
+        }
+
+        fn cell(&self, x: usize, y: usize) -> Span<'static> {
+            Span::raw(if x + 3 * y == self.value % 6 {
+                "#"
+            } else {
+                "."
+            })
+        }
+
+        fn details(&self, _cursor: (usize, usize)) -> Vec<Line<'static>> {
+            vec![Line::from(format!("Value: {}", self.value))]
+        }
+
+        fn is_finished(&self) -> bool {
+            self.wrap.is_none() && self.value >= self.limit
+        }
+
+        fn fingerprint(&self) -> Option<u64> {
+            self.wrap.map(|_| self.value as u64)
+        }
+    }
+
+    #[test]
+    fn steps_forward_and_back() {
+        let mut explorer = Explorer::new(Counter::new(500, None));
+        explorer.jump(200, true);
+        assert_eq!(200, explorer.step());
+        assert_eq!(200, explorer.state().value);
+        let taken = explorer.state().steps_taken.get();
+        explorer.jump(1, false);
+        assert_eq!(199, explorer.state().value);
+        assert!(explorer.state().steps_taken.get() - taken < CHECKPOINT_INTERVAL);
+        explorer.jump(10, false);
+        assert_eq!(189, explorer.state().value);
+        explorer.go_to(1000);
+        assert_eq!(500, explorer.step());
+        assert!(explorer.state().is_finished());
+        explorer.jump(1000, false);
+        assert_eq!(0, explorer.state().value);
+    }
+
+    #[test]
+    fn skips_through_cycles() {
+        let mut explorer = Explorer::new(Counter::new(0, Some(7)));
+        explorer.go_to(MAX_COUNT);
+        assert_eq!(MAX_COUNT, explorer.step());
+        assert_eq!(MAX_COUNT % 7, explorer.state().value);
+        assert!(explorer.state().steps_taken.get() < 20);
+        explorer.jump(3, false);
+        assert_eq!((MAX_COUNT - 3) % 7, explorer.state().value);
+    }
+
+    #[test]
+    fn handles_counted_keys() {
+        let mut explorer = Explorer::new(Counter::new(100, None));
+        assert!(explorer.handle_key(KeyCode::Char('1')));
+        assert!(explorer.handle_key(KeyCode::Char('2')));
+        assert!(explorer.handle_key(KeyCode::Char('n')));
+        assert_eq!(12, explorer.step());
+        assert!(explorer.handle_key(KeyCode::Char('p')));
+        assert_eq!(11, explorer.step());
+        assert!(explorer.handle_key(KeyCode::Char('g')));
+        assert_eq!(0, explorer.step());
+        for _ in 0..40 {
+            explorer.handle_key(KeyCode::Char('9'));
+        }
+        assert_eq!(Some(MAX_COUNT), explorer.count);
+        assert!(explorer.handle_key(KeyCode::Char('p')));
+        assert_eq!(0, explorer.step());
+        assert!(!explorer.handle_key(KeyCode::Esc));
+        assert!(!explorer.handle_key(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn cursor_stays_on_the_grid() {
+        let mut explorer = Explorer::new(Counter::new(1, None));
+        explorer.handle_key(KeyCode::Left);
+        explorer.handle_key(KeyCode::Up);
+        assert_eq!((0, 0), explorer.cursor());
+        for _ in 0..5 {
+            explorer.handle_key(KeyCode::Right);
+            explorer.handle_key(KeyCode::Down);
+        }
+        assert_eq!((2, 1), explorer.cursor());
+    }
+
+    #[test]
+    fn renders_grid_and_state() {
+        let mut explorer = Explorer::new(Counter::new(10, None));
+        explorer.go_to(4);
+        explorer.handle_key(KeyCode::Char('7'));
+        let lines = render_to_lines(&explorer, 50, 8);
+        assert!(lines[0].contains("Counter"));
+        assert!(lines[1].starts_with("│..."));
+        assert!(lines[2].starts_with("│.#."));
+        assert!(lines.iter().any(|line| line.contains("Tick: 4")));
+        assert!(lines.iter().any(|line| line.contains("Value: 4")));
+        assert!(lines.iter().any(|line| line.contains("Count: 7")));
+        assert!(lines[7].starts_with("n step"));
+        explorer.go_to(10);
+        let lines = render_to_lines(&explorer, 50, 8);
+        assert!(lines.iter().any(|line| line.contains("Tick: 10 (done)")));
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2023 CJ Harries
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! How every day's binary finds its input file on the command line.
+
+use std::env::args;
+use std::fs::read_to_string;
+
+/// The input file used when none is given.
+pub const DEFAULT_PATH: &str = "input.txt";
+
+/// Finds the first argument that is neither a `--flag` nor the value of one
+/// of the `valued` flags, falling back to `DEFAULT_PATH`.
+pub fn path<I: IntoIterator<Item = String>>(args: I, valued: &[&str]) -> String {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if valued.contains(&arg.as_str()) {
+            args.next();
+        } else if !arg.starts_with("--") {
+            return arg;
+        }
+    }
+    DEFAULT_PATH.to_string()
+}
+
+/// Reads the input file named among `args`.
+#[cfg(not(tarpaulin_include))]
+pub fn read_from<I: IntoIterator<Item = String>>(args: I, valued: &[&str]) -> String {
+    read_to_string(path(args, valued)).expect("Unable to read input file")
+}
+
+/// Reads the input file named on the command line.
+#[cfg(not(tarpaulin_include))]
+pub fn read(valued: &[&str]) -> String {
+    read_from(args().skip(1), valued)
+}
+
+#[cfg(not(tarpaulin_include))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn defaults_without_a_path() {
+        assert_eq!(DEFAULT_PATH, path(strings(&[]), &[]));
+        assert_eq!(DEFAULT_PATH, path(strings(&["--fmt", "--json"]), &[]));
+    }
+
+    #[test]
+    fn finds_the_first_positional_argument() {
+        assert_eq!("a.txt", path(strings(&["--fmt", "a.txt", "b.txt"]), &[]));
+    }
+
+    #[test]
+    fn skips_values_of_valued_flags() {
+        let args = strings(&["--rules", "rules.txt", "--check", "a.txt"]);
+        assert_eq!("a.txt", path(args.clone(), &["--rules"]));
+        assert_eq!("rules.txt", path(args, &[]));
+    }
+
+    #[test]
+    fn takes_a_path_that_follows_a_value() {
+        let args = strings(&["a.txt", "--model", "linear"]);
+        assert_eq!("a.txt", path(args, &["--model"]));
+    }
+}